@@ -0,0 +1,61 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+pub fn parse(data: &str) -> Result<Vec<u32>, ParseError> {
+    let mut totals = Vec::new();
+    let mut current_total = 0;
+
+    for line in data.lines() {
+        if line.is_empty() {
+            totals.push(current_total);
+            current_total = 0;
+        } else {
+            let calories: u32 = parse::number(line)?;
+            current_total += calories;
+        }
+    }
+
+    totals.push(current_total);
+    totals.sort();
+
+    Ok(totals)
+}
+
+pub fn part_one(totals: &[u32]) -> u32 {
+    totals.iter().max().copied().unwrap_or(0)
+}
+
+pub fn part_two(totals: &[u32]) -> u32 {
+    totals.iter().rev().take(3).sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<u32>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day01>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,200 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+const WIDTH: usize = 40;
+const HEIGHT: usize = 6;
+
+/// The letters the CRT draws, each 4 pixels wide and followed by a blank column.
+const LETTERS: &str = "ABCEFGHJKLOPRSUZ";
+const FONT: [&str; HEIGHT] = [
+    ".##..###...##..####.####..##..#..#...##.#..#.#.....##..###..###...###.#..#.####.",
+    "#..#.#..#.#..#.#....#....#..#.#..#....#.#.#..#....#..#.#..#.#..#.#....#..#....#.",
+    "#..#.###..#....###..###..#....####....#.##...#....#..#.#..#.#..#.#....#..#...#..",
+    "####.#..#.#....#....#....#.##.#..#....#.#.#..#....#..#.###..###...##..#..#..#...",
+    "#..#.#..#.#..#.#....#....#..#.#..#.#..#.#.#..#....#..#.#....#.#.....#.#..#.#....",
+    "#..#.###...##..####.#.....###.#..#..##..#..#.####..##..#....#..#.###...##..####.",
+];
+
+/// The `index`th letter of a row of pixels, with its blank column.
+fn cell(row: &str, index: usize) -> &str {
+    &row[index * 5..index * 5 + 5]
+}
+
+pub struct Screen {
+    pixels: [[bool; WIDTH]; HEIGHT],
+}
+
+impl Screen {
+    fn new() -> Self {
+        Screen { pixels: [[false; WIDTH]; HEIGHT] }
+    }
+
+    /// The pixels as `#` and `.`, one line per row.
+    pub fn render(&self) -> String {
+        let mut image = String::new();
+
+        for row in &self.pixels {
+            image.extend(row.iter().map(|&lit| if lit { '#' } else { '.' }));
+            image.push('\n');
+        }
+
+        image
+    }
+
+    /// The letters on the screen, or `None` if any of them isn't in the font.
+    pub fn read(&self) -> Option<String> {
+        let image = self.render();
+        let rows: Vec<&str> = image.lines().collect();
+
+        (0..WIDTH / 5)
+            .map(|index| {
+                LETTERS.chars().enumerate().find_map(|(letter_index, letter)| {
+                    rows.iter()
+                        .zip(FONT)
+                        .all(|(row, font_row)| cell(row, index) == cell(font_row, letter_index))
+                        .then_some(letter)
+                })
+            })
+            .collect()
+    }
+}
+
+pub enum Operation {
+    Noop,
+    AddX(i32),
+}
+
+struct Cpu {
+    register: i32,
+    cycle: i32,
+    signal_strength: i32,
+    screen: Screen,
+}
+
+impl Cpu {
+    fn new() -> Self {
+        Cpu {
+            register: 1,
+            cycle: 1,
+            signal_strength: 0,
+            screen: Screen::new(),
+        }
+    }
+
+    fn execute(&mut self, operation: &Operation) {
+        if (self.cycle + 20) % 40 == 0 {
+            self.signal_strength += self.cycle * self.register;
+        }
+
+        let position = (self.cycle - 1) as usize;
+        let column = (self.cycle - 1) % WIDTH as i32;
+
+        if (self.register - 1..=self.register + 1).contains(&column) && position < WIDTH * HEIGHT {
+            self.screen.pixels[position / WIDTH][position % WIDTH] = true;
+        }
+
+        match operation {
+            Operation::Noop => {}
+            Operation::AddX(amount) => {
+                self.register += amount;
+            }
+        }
+
+        self.cycle += 1;
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Operation>, ParseError> {
+    let mut operations: Vec<Operation> = Vec::new();
+    for line in data.lines() {
+        let parts = line.split_once(' ');
+
+        match parts {
+            None if line == "noop" => {
+                operations.push(Operation::Noop);
+            }
+            Some(("addx", amount)) => {
+                operations.push(Operation::Noop);
+                operations.push(Operation::AddX(parse::number(amount)?));
+            },
+            _ => return Err(ParseError::new(line, "'noop' or 'addx <amount>'")),
+        }
+    }
+
+    Ok(operations)
+}
+
+fn run(operations: &[Operation]) -> Cpu {
+    let mut cpu = Cpu::new();
+
+    for operation in operations {
+        cpu.execute(operation);
+    }
+
+    cpu
+}
+
+pub fn part_one(operations: &[Operation]) -> i32 {
+    run(operations).signal_strength
+}
+
+pub fn part_two(operations: &[Operation]) -> Screen {
+    run(operations).screen
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Operation>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let screen = part_two(input);
+
+        match screen.read() {
+            Some(letters) => letters.into(),
+            None => Answer::Art(screen.render().trim_end().to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day10>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_read() {
+        let mut screen = Screen::new();
+        let letters = "PLEZKURJ";
+
+        for (index, letter) in letters.chars().enumerate() {
+            let letter_index = LETTERS.find(letter).unwrap();
+
+            for (y, font_row) in FONT.iter().enumerate() {
+                for (x, c) in cell(font_row, letter_index).chars().enumerate() {
+                    screen.pixels[y][index * 5 + x] = c == '#';
+                }
+            }
+        }
+
+        assert_eq!(Some(letters.to_string()), screen.read());
+
+        screen.pixels[0][0] = !screen.pixels[0][0];
+        assert_eq!(None, screen.read());
+    }
+}
@@ -0,0 +1,169 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    inspected_item_count: u64,
+    operation: Operation,
+    test: u64,
+    success_target: usize,
+    fail_target: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square
+}
+
+fn next_line<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    input: &'a str,
+    prefix: &str,
+) -> Result<&'a str, ParseError> {
+    let line = lines.next().ok_or_else(|| ParseError::after(input, format!("'{}'", prefix)))?;
+    parse::strip_prefix(line.trim_start(), prefix)
+}
+
+fn parse_target(input: &str, monkey_count: usize) -> Result<usize, ParseError> {
+    match parse::number(input)? {
+        target if target < monkey_count => Ok(target),
+        _ => Err(ParseError::new(input, format!("a monkey below {}", monkey_count))),
+    }
+}
+
+fn parse_monkey(input: &str, monkey_count: usize) -> Result<Monkey, ParseError> {
+    let mut lines = input.lines();
+
+    next_line(&mut lines, input, "Monkey ")?;
+    let items = parse_items(next_line(&mut lines, input, "Starting items: ")?)?;
+    let operation = parse_operation(next_line(&mut lines, input, "Operation: new = old ")?)?;
+    let test = parse::number(next_line(&mut lines, input, "Test: divisible by ")?)?;
+    let success_target = next_line(&mut lines, input, "If true: throw to monkey ")?;
+    let success_target = parse_target(success_target, monkey_count)?;
+    let fail_target = next_line(&mut lines, input, "If false: throw to monkey ")?;
+    let fail_target = parse_target(fail_target, monkey_count)?;
+
+    Ok(Monkey {
+        items,
+        inspected_item_count: 0,
+        operation,
+        test,
+        success_target,
+        fail_target,
+    })
+}
+
+fn parse_items(input: &str) -> Result<Vec<u64>, ParseError> {
+    input
+        .split(", ")
+        .map(parse::number)
+        .collect()
+}
+
+fn parse_operation(input: &str) -> Result<Operation, ParseError> {
+    match parse::split_once(input, " ")? {
+        ("+", amount) => Ok(Operation::Add(parse::number(amount)?)),
+        ("*", "old") => Ok(Operation::Square),
+        ("*", factor) => Ok(Operation::Multiply(parse::number(factor)?)),
+        (operator, _) => Err(ParseError::new(operator, "'+' or '*'")),
+    }
+}
+
+fn run_round(monkeys: &mut [Monkey], calm_down: bool) {
+    let d: u64 = monkeys.iter().map(|monkey| monkey.test).product();
+
+    for i in 0..monkeys.len() {
+        for item_i in 0..monkeys[i].items.len() {
+            let worry_amount = monkeys[i].items[item_i];
+            let mut new_worry_amount = match monkeys[i].operation {
+                Operation::Add(amount) => worry_amount + amount,
+                Operation::Multiply(factor) => worry_amount * factor,
+                Operation::Square => worry_amount * worry_amount,
+            };
+
+            if calm_down {
+                new_worry_amount /= 3;
+            }
+
+            new_worry_amount %= d;
+
+            if new_worry_amount % monkeys[i].test == 0 {
+                let target_index = monkeys[i].success_target;
+                monkeys[target_index].items.push(new_worry_amount);
+            } else {
+                let target_index = monkeys[i].fail_target;
+                monkeys[target_index].items.push(new_worry_amount);
+            }
+
+            monkeys[i].inspected_item_count += 1;
+        }
+
+        monkeys[i].items.clear();
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkey_count = data.split("\n\n").count();
+
+    data.split("\n\n").map(|monkey| parse_monkey(monkey, monkey_count)).collect()
+}
+
+fn monkey_business(monkeys: &[Monkey]) -> u64 {
+    let mut item_counts: Vec<u64> = monkeys.iter().map(|monkey| monkey.inspected_item_count).collect();
+    item_counts.sort();
+    item_counts.into_iter().rev().take(2).reduce(|a, b| a * b).unwrap()
+}
+
+pub fn part_one(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+
+    for _ in 0..20 {
+        run_round(&mut monkeys, true);
+    }
+
+    monkey_business(&monkeys)
+}
+
+pub fn part_two(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+
+    for _ in 0..10000 {
+        run_round(&mut monkeys, false);
+    }
+
+    monkey_business(&monkeys)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day11>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
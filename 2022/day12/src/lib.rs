@@ -0,0 +1,190 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use aoc_search::bfs;
+use std::collections::HashMap;
+
+type Point = (i64, i64);
+
+struct MapSquare {
+    height: i64,
+}
+
+pub struct Map {
+    grid: Vec<Vec<MapSquare>>,
+    distances: HashMap<Point, usize>,
+    start: Point,
+    end: Point,
+    width: i64,
+    height: i64,
+}
+
+impl Map {
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let mut grid = Vec::new();
+        let mut start: Option<Point> = None;
+        let mut end: Option<Point> = None;
+        let mut x: i64 = 0;
+        let mut y: i64 = 0;
+
+        for line in data.lines() {
+            let mut row = Vec::new();
+            x = 0;
+            for (i, height_char) in line.char_indices() {
+                match height_char {
+                    'E' => {
+                        end = Some((x, y));
+                        row.push(MapSquare { height: 25 });
+                    }
+                    'S' => {
+                        start = Some((x, y));
+                        row.push(MapSquare { height: 0 });
+                    }
+                    'a'..='z' => row.push(MapSquare { height: height_char as i64 - 97 }),
+                    _ => {
+                        let token = &line[i..i + height_char.len_utf8()];
+                        return Err(ParseError::new(token, "'S', 'E' or a letter from 'a' to 'z'"));
+                    }
+                }
+                x += 1;
+            }
+            if grid.first().is_some_and(|first: &Vec<MapSquare>| first.len() != row.len()) {
+                return Err(ParseError::new(line, format!("{} squares", grid[0].len())));
+            }
+            grid.push(row);
+            y += 1;
+        }
+
+        Ok(Map {
+            grid,
+            distances: HashMap::new(),
+            start: start.ok_or_else(|| ParseError::after(data, "a start square 'S'"))?,
+            end: end.ok_or_else(|| ParseError::after(data, "an end square 'E'"))?,
+            width: x,
+            height: y,
+        })
+    }
+
+    fn contains(&self, point: &Point) -> bool {
+        if point.0 < 0 || point.1 < 0 {
+            return false;
+        }
+
+        (0..self.width).contains(&point.0) && (0..self.height).contains(&point.1)
+    }
+
+    fn height(&self, point: &Point) -> i64 {
+        self.grid[point.1 as usize][point.0 as usize].height
+    }
+
+    fn distance(&self, point: &Point) -> i64 {
+        self.distances.get(point).map_or(i64::MAX, |&distance| distance as i64)
+    }
+
+    fn neighbours(&self, point: &Point) -> Vec<Point> {
+        let edges = [
+            (point.0 + 1, point.1),
+            (point.0, point.1 + 1),
+            (point.0 - 1, point.1),
+            (point.0, point.1 - 1),
+        ];
+
+        edges
+            .into_iter()
+            .filter(|p| self.contains(p) && self.height(point) - 1 <= self.height(p))
+            .collect()
+    }
+
+    fn calculate_distances(&mut self) {
+        let search = bfs([self.end], |point| self.neighbours(point));
+
+        self.distances = search.distances().clone();
+    }
+}
+
+pub fn parse(data: &str) -> Result<Map, ParseError> {
+    let mut map = Map::parse(data)?;
+    map.calculate_distances();
+    Ok(map)
+}
+
+pub fn part_one(map: &Map) -> i64 {
+    map.distance(&map.start)
+}
+
+pub fn part_two(map: &Map) -> i64 {
+    let mut shortest = i64::MAX;
+    for x in 0..map.width {
+        for y in 0..map.height {
+            let point = &(x, y);
+            if map.height(point) == 0 {
+                shortest = i64::min(shortest, map.distance(point));
+            }
+        }
+    }
+
+    shortest
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+
+    type Input<'a> = Map;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_contains() {
+        let map = Map::parse(&fs::read_to_string("fixtures/example.txt").expect("Can't read input file")).unwrap();
+
+        assert!(map.contains(&(0, 0)));
+        assert!(map.contains(&(0, 1)));
+        assert!(map.contains(&(1, 0)));
+        assert!(map.contains(&(1, 1)));
+
+        assert!(!map.contains(&(-1, 1)));
+        assert!(!map.contains(&(0, 100)));
+    }
+
+    #[test]
+    fn test_height() {
+        let map = Map::parse(&fs::read_to_string("fixtures/example.txt").expect("Can't read input file")).unwrap();
+
+        assert_eq!(0, map.height(&(0, 0)));
+        assert_eq!(0, map.height(&(1, 0)));
+        assert_eq!(0, map.height(&(0, 1)));
+        assert_eq!(1, map.height(&(1, 1)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let map = Map::parse(&fs::read_to_string("fixtures/example.txt").expect("Can't read input file")).unwrap();
+
+        let neighbours = map.neighbours(&(0, 0));
+
+        assert_eq!(2, neighbours.len());
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day12>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
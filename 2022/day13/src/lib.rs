@@ -0,0 +1,244 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::cmp::Ordering;
+use std::slice::Iter;
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    OpenParen,
+    CloseParen,
+    Number(u64),
+}
+
+#[derive(Debug)]
+pub enum Item {
+    List(Vec<Item>),
+    Value(u64),
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Value(self_value), Self::Value(other_value)) => self_value.cmp(other_value),
+            (Self::List(self_items), Self::List(other_items)) => self_items.cmp(other_items),
+            (Self::Value(self_value), Self::List(other_items)) => {
+                vec![Self::Value(*self_value)].cmp(other_items)
+            }
+            (Self::List(self_items), Self::Value(other_value)) => {
+                self_items.cmp(&vec![Self::Value(*other_value)])
+            }
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Item {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Value(self_value), Self::Value(other_value)) => self_value.eq(other_value),
+            (Self::List(self_items), Self::List(other_items)) => self_items.eq(other_items),
+            (Self::Value(self_value), Self::List(other_items)) => {
+                vec![Self::Value(*self_value)].eq(other_items)
+            }
+            (Self::List(self_items), Self::Value(other_value)) => {
+                self_items.eq(&vec![Self::Value(*other_value)])
+            }
+        }
+    }
+}
+
+impl Eq for Item {}
+
+fn lex_line(line: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut number_buffer: Option<String> = None;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            '[' => {
+                tokens.push(Token::OpenParen);
+            }
+            ']' => {
+                if let Some(number_str) = number_buffer {
+                    tokens.push(Token::Number(number_str.parse().expect("impossible")));
+                    number_buffer = None;
+                }
+                tokens.push(Token::CloseParen);
+            }
+            '0'..='9' => {
+                if let Some(mut number_string) = number_buffer {
+                    number_string.push(c);
+                    number_buffer = Some(number_string);
+                } else {
+                    number_buffer = Some(String::from(c));
+                }
+            }
+            ',' => {
+                if let Some(number_str) = number_buffer {
+                    tokens.push(Token::Number(number_str.parse().expect("impossible")));
+                    number_buffer = None;
+                }
+            }
+            _ => {
+                let token = &line[i..i + c.len_utf8()];
+                return Err(ParseError::new(token, "'[', ']', ',' or a digit"));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Checks that `line` is one list with balanced brackets.
+fn check_brackets(line: &str) -> Result<(), ParseError> {
+    if !line.starts_with('[') {
+        let first = line.chars().next().map_or(0, char::len_utf8);
+        return Err(ParseError::new(&line[..first], "'['"));
+    }
+
+    let mut depth = 0;
+    for (i, c) in line.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            _ => {}
+        }
+
+        if depth == 0 && i + 1 < line.len() {
+            return Err(ParseError::new(&line[i + 1..], "end of line"));
+        }
+    }
+
+    if depth > 0 {
+        return Err(ParseError::after(line, "']'"));
+    }
+
+    Ok(())
+}
+
+fn parse_line(line: &str) -> Result<Item, ParseError> {
+    check_brackets(line)?;
+    let tokens = lex_line(line)?;
+    let mut tokens = tokens.iter();
+    // Remove starting OpenParam
+    tokens.next();
+
+    Ok(parse_list(&mut tokens))
+}
+
+fn parse_list(tokens: &mut Iter<'_, Token>) -> Item {
+    let mut items = Vec::new();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::CloseParen => {
+                break;
+            }
+            Token::Number(number) => {
+                items.push(Item::Value(*number));
+            }
+            Token::OpenParen => {
+                items.push(parse_list(tokens));
+            }
+        }
+    }
+
+    Item::List(items)
+}
+
+pub fn parse(data: &str) -> Result<Vec<(Item, Item)>, ParseError> {
+    data.split("\n\n")
+        .map(|pair_str| {
+            let [left, right] = parse::split_n(pair_str.trim_end(), "\n")?;
+
+            Ok((parse_line(left)?, parse_line(right)?))
+        })
+        .collect()
+}
+
+pub fn part_one(pairs: &[(Item, Item)]) -> usize {
+    pairs.iter()
+        .enumerate()
+        .map(|(index, (left, right))| {
+            if left.cmp(right) == Ordering::Less {
+                index + 1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+pub fn part_two(pairs: &[(Item, Item)]) -> usize {
+    let divider = [
+        Item::List(vec![Item::List(vec![Item::Value(2)])]),
+        Item::List(vec![Item::List(vec![Item::Value(6)])]),
+    ];
+
+    let mut lines: Vec<&Item> = pairs
+        .iter()
+        .flat_map(|(left, right)| [left, right])
+        .chain(&divider)
+        .collect();
+
+    lines.sort();
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| if divider.contains(line) { index + 1 } else { 1 })
+        .reduce(|a, b| a * b)
+        .expect("error")
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<(Item, Item)>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lex_line() {
+        let line = "[1,2,34,[1]]";
+        let tokens = lex_line(line).unwrap();
+
+        assert_eq!(8, tokens.len());
+        assert_eq!(Token::OpenParen, tokens[0]);
+        assert_eq!(Token::Number(1), tokens[1]);
+        assert_eq!(Token::Number(2), tokens[2]);
+        assert_eq!(Token::Number(34), tokens[3]);
+        assert_eq!(Token::OpenParen, tokens[4]);
+        assert_eq!(Token::Number(1), tokens[5]);
+        assert_eq!(Token::CloseParen, tokens[6]);
+        assert_eq!(Token::CloseParen, tokens[7]);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day13>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,327 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+#[derive(Clone)]
+pub struct Point {
+    x: u64,
+    y: u64,
+}
+
+struct Limits {
+    min_x: u64,
+    max_x: u64,
+    min_y: u64,
+    max_y: u64,
+}
+
+#[derive(Clone, PartialEq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+struct Map {
+    offset_x: u64,
+    tiles: VecDeque<Vec<Tile>>,
+}
+
+enum TikResult {
+    Falling(Point),
+    Settled,
+    Finished,
+}
+
+#[derive(PartialEq)]
+enum ContainResult {
+    Contains,
+    LeavesX,
+    LeavesY,
+}
+
+impl Point {
+    fn range_x(&self, other: &Point) -> RangeInclusive<u64> {
+        if self.x < other.x {
+            self.x..=other.x
+        } else {
+            other.x..=self.x
+        }
+    }
+
+    fn range_y(&self, other: &Point) -> RangeInclusive<u64> {
+        if self.y < other.y {
+            self.y..=other.y
+        } else {
+            other.y..=self.y
+        }
+    }
+
+    fn down(&self) -> Point {
+        Point {
+            x: self.x,
+            y: self.y + 1,
+        }
+    }
+
+    fn down_right(&self) -> Point {
+        Point {
+            x: self.x + 1,
+            y: self.y + 1,
+        }
+    }
+
+    fn down_left(&self) -> Point {
+        Point {
+            x: self.x - 1,
+            y: self.y + 1,
+        }
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.tiles[0].len() {
+            for column in &self.tiles {
+                write!(
+                    f,
+                    "{}",
+                    match column[y] {
+                        Tile::Air => '.',
+                        Tile::Sand => 'o',
+                        Tile::Rock => '#',
+                    }
+                )?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Map {
+    fn from_paths(paths: &[Vec<Point>], with_bottom: bool) -> Self {
+        let limits = find_limits(paths);
+
+        Self::build(&limits, paths, with_bottom)
+    }
+
+    fn build(limits: &Limits, paths: &[Vec<Point>], with_bottom: bool) -> Self {
+        let mut map = Map {
+            offset_x: limits.min_x - 1,
+            tiles: VecDeque::new(),
+        };
+
+        let height = if with_bottom {
+            limits.max_y + 3
+        } else {
+            limits.max_y + 1
+        } as usize;
+
+        for x in map.offset_x..limits.max_x + 2 {
+            map.tiles.push_back(vec![Tile::Air; height]);
+
+            if with_bottom {
+                map.tiles[x as usize - map.offset_x as usize][height - 1] = Tile::Rock
+            }
+        }
+
+        for path in paths {
+            for i in 0..path.len() - 1 {
+                let (start, end) = (&path[i], &path[i + 1]);
+
+                for x in start.range_x(end) {
+                    for y in start.range_y(end) {
+                        map.set_tile(&Point { x, y }, Tile::Rock);
+                    }
+                }
+            }
+        }
+
+        map
+    }
+
+    fn set_tile(&mut self, point: &Point, tile: Tile) {
+        self.tiles[(point.x - self.offset_x) as usize][point.y as usize] = tile;
+    }
+
+    fn get_tile(&self, point: &Point) -> &Tile {
+        &self.tiles[(point.x - self.offset_x) as usize][point.y as usize]
+    }
+
+    fn is_blocked(&self, point: &Point) -> bool {
+        self.get_tile(point) != &Tile::Air
+    }
+
+    fn contains(&self, point: &Point) -> ContainResult {
+        if point.x < self.offset_x || point.x as usize >= self.tiles.len() + self.offset_x as usize
+        {
+            ContainResult::LeavesX
+        } else if !(0..self.tiles[0].len()).contains(&(point.y as usize)) {
+            ContainResult::LeavesY
+        } else {
+            ContainResult::Contains
+        }
+    }
+
+    fn grow_x(&mut self, x: u64) {
+        let height = self.tiles[0].len();
+        let mut new_row = vec![Tile::Air; height];
+        new_row[height - 1] = Tile::Rock;
+
+        if x < self.offset_x {
+            self.tiles.push_front(new_row);
+            self.offset_x -= 1;
+        } else {
+            self.tiles.push_back(new_row);
+        }
+    }
+
+    fn tik(&mut self, point: &Point) -> TikResult {
+        if self.is_blocked(point) {
+            return TikResult::Finished;
+        }
+
+        let down_point = point.down();
+
+        if self.contains(&down_point) != ContainResult::Contains {
+            return TikResult::Finished;
+        }
+        if !self.is_blocked(&down_point) {
+            return TikResult::Falling(down_point);
+        }
+
+        let down_point = point.down_left();
+
+        if self.contains(&down_point) == ContainResult::LeavesX {
+            self.grow_x(down_point.x);
+        }
+
+        if !self.is_blocked(&down_point) {
+            return TikResult::Falling(down_point);
+        }
+
+        let down_point = point.down_right();
+
+        if self.contains(&down_point) == ContainResult::LeavesX {
+            self.grow_x(down_point.x);
+        }
+
+        if !self.is_blocked(&down_point) {
+            return TikResult::Falling(down_point);
+        }
+
+        self.set_tile(point, Tile::Sand);
+
+        TikResult::Settled
+    }
+}
+
+fn parse_line(line: &str) -> Result<Vec<Point>, ParseError> {
+    line.split(" -> ")
+        .map(|point_data| {
+            let (x, y) = parse::split_once(point_data, ",")?;
+
+            Ok(Point {
+                x: parse::number(x)?,
+                y: parse::number(y)?,
+            })
+        })
+        .collect()
+}
+
+fn find_limits(paths: &[Vec<Point>]) -> Limits {
+    let mut limits = Limits {
+        min_x: u64::MAX,
+        max_x: u64::MIN,
+        min_y: u64::MAX,
+        max_y: u64::MIN,
+    };
+
+    for path in paths {
+        for point in path {
+            limits.min_x = limits.min_x.min(point.x);
+            limits.max_x = limits.max_x.max(point.x);
+            limits.min_y = limits.min_y.min(point.y);
+            limits.max_y = limits.max_y.max(point.y);
+        }
+    }
+
+    limits
+}
+
+fn run(map: &mut Map) -> u64 {
+    let start_point = Point { x: 500, y: 0 };
+    let mut point = start_point.clone();
+    let mut sand_counter = 0;
+
+    loop {
+        match map.tik(&point) {
+            TikResult::Finished => break,
+            TikResult::Falling(new_point) => point = new_point,
+            TikResult::Settled => {
+                point = start_point.clone();
+                sand_counter += 1;
+            }
+        }
+    }
+
+    sand_counter
+}
+
+pub fn parse(data: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+    let paths = data
+        .lines()
+        .map(parse_line)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if paths.is_empty() {
+        return Err(ParseError::after(data, "a rock path"));
+    }
+
+    Ok(paths)
+}
+
+pub fn part_one(paths: &[Vec<Point>]) -> u64 {
+    let mut map = Map::from_paths(paths, false);
+    run(&mut map)
+}
+
+pub fn part_two(paths: &[Vec<Point>]) -> u64 {
+    let mut map = Map::from_paths(paths, true);
+    run(&mut map)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Vec<Vec<Point>>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day14>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,203 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Params, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq, Hash, Eq)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+impl Point {
+    fn distance(&self, other: &Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+pub struct Sensor {
+    location: Point,
+    closest_beacon: Point,
+    distance: i64,
+}
+
+impl Sensor {
+    fn new(location: Point, closest_beacon: Point) -> Self {
+        let distance = location.distance(&closest_beacon);
+        Self {
+            location,
+            closest_beacon,
+            distance,
+        }
+    }
+    fn covers(&self, point: &Point) -> bool {
+        self.distance >= self.location.distance(point)
+    }
+}
+
+fn parse_point(input: &str) -> Result<Point, ParseError> {
+    let (_, coordinates) = parse::split_once(input, " at x=")?;
+    let (x, y) = parse::split_once(coordinates, ", y=")?;
+
+    Ok(Point {
+        x: parse::number(x)?,
+        y: parse::number(y)?,
+    })
+}
+
+fn parse_line(line: &str) -> Result<Sensor, ParseError> {
+    let (left, right) = parse::split_once(line, ":")?;
+
+    Ok(Sensor::new(parse_point(left)?, parse_point(right)?))
+}
+
+pub fn parse(data: &str) -> Result<Vec<Sensor>, ParseError> {
+    data.lines().map(parse_line).collect()
+}
+
+pub fn part_one(sensors: &[Sensor], y: i64) -> i64 {
+    let (min_x, max_x) = sensors
+        .iter()
+        .fold((i64::MAX, i64::MIN), |(min_x, max_x), sensor| {
+            (
+                min_x.min(sensor.location.x - sensor.distance),
+                max_x.max(sensor.location.x + sensor.distance),
+            )
+        });
+
+    let mut beacons = HashSet::new();
+
+    for sensor in sensors {
+        if sensor.closest_beacon.y == y {
+            beacons.insert(&sensor.closest_beacon);
+        }
+    }
+
+    let beacon_count = beacons.len() as i64;
+
+    let mut x = min_x;
+    let mut tile_count = 0;
+
+    while x <= max_x {
+        let p = Point { x, y };
+        let mut new_x = None;
+
+        for sensor in sensors {
+            if sensor.covers(&p) {
+                new_x = Some(
+                    sensor.location.x + 1 + (sensor.distance - (sensor.location.y - p.y).abs()),
+                );
+                break;
+            }
+        }
+
+        if let Some(new_x) = new_x {
+            x = new_x.min(max_x);
+            tile_count += x - p.x;
+        } else {
+            x += 1;
+        }
+    }
+
+    tile_count - beacon_count
+}
+
+pub fn part_two(sensors: &[Sensor], limit: i64) -> i64 {
+    for y in 0..=limit {
+        let mut x = 0;
+
+        while x <= limit {
+            let p = Point { x, y };
+            let mut new_x = None;
+
+            for sensor in sensors {
+                if sensor.covers(&p) {
+                    new_x = Some(
+                        sensor.location.x + 1 + (sensor.distance - (sensor.location.y - p.y).abs()),
+                    );
+                    break;
+                }
+            }
+
+            if let Some(new_x) = new_x {
+                x = new_x.min(limit + 1);
+            } else {
+                return p.x * 4000000 + p.y;
+            }
+        }
+    }
+
+    0
+}
+
+pub struct Day15;
+
+pub struct Scan {
+    sensors: Vec<Sensor>,
+    row: i64,
+    limit: i64,
+}
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Scan;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(data, &Params::default())
+    }
+
+    fn parse_with<'a>(data: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok(Scan {
+            sensors: parse(data)?,
+            row: params.get("row", 2_000_000),
+            limit: params.get("limit", 4_000_000),
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(&input.sensors, input.row).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(&input.sensors, input.limit).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    fn parse_file(file: &str) -> Vec<Sensor> {
+        parse(&fs::read_to_string(file).expect("Can't read input file")).unwrap()
+    }
+
+    #[test]
+    fn test_parse_point() {
+        let point1 = parse_point("Sensor at x=2, y=18").unwrap();
+        let point2 = parse_point("closest beacon is at x=-2, y=15").unwrap();
+
+        assert_eq!(Point { x: 2, y: 18 }, point1);
+        assert_eq!(Point { x: -2, y: 15 }, point2);
+    }
+
+    #[test]
+    fn test_part_one_small() {
+        let sensors = parse_file("fixtures/example.txt");
+
+        assert_eq!(26, part_one(&sensors, 10));
+    }
+
+    #[test]
+    fn test_part_two_small() {
+        let sensors = parse_file("fixtures/example.txt");
+
+        assert_eq!(56000011, part_two(&sensors, 20));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day15>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
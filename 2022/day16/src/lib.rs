@@ -0,0 +1,566 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(PartialEq, Debug)]
+struct Node {
+    id: u32,
+    flow_rate: u32,
+    neighbours: BTreeSet<u32>,
+}
+
+fn parse_node_id(id: &str) -> u32 {
+    u32::from_str_radix(id, 36).expect("Invalid input")
+}
+
+fn parse_valve(id: &str) -> Result<u32, ParseError> {
+    if id.len() == 2 && id.chars().all(|c| c.is_ascii_uppercase()) {
+        Ok(parse_node_id(id))
+    } else {
+        Err(ParseError::new(id, "a valve name"))
+    }
+}
+
+fn fmt_node_id(id: u32) -> String {
+    let first_char = id / 36;
+    let second_char = id - first_char * 36;
+
+    let id_str = [
+        char::from_digit(first_char, 36)
+            .unwrap()
+            .to_ascii_uppercase(),
+        char::from_digit(second_char, 36)
+            .unwrap()
+            .to_ascii_uppercase(),
+    ];
+
+    id_str.iter().collect()
+}
+
+fn parse_line(line: &str) -> Result<Node, ParseError> {
+    let (valve, tunnels) = parse::split_once(line, "; ")?;
+    let valve = parse::strip_prefix(valve, "Valve ")?;
+    let (id, flow_rate) = parse::split_once(valve, " has flow rate=")?;
+    let neighbours = ["tunnels lead to valves ", "tunnel leads to valve "]
+        .iter()
+        .find_map(|prefix| tunnels.strip_prefix(prefix))
+        .ok_or_else(|| ParseError::new(tunnels, "'tunnels lead to valves'"))?;
+
+    Ok(Node {
+        id: parse_valve(id)?,
+        flow_rate: parse::number(flow_rate)?,
+        neighbours: neighbours.split(", ").map(parse_valve).collect::<Result<_, _>>()?,
+    })
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    nodes: BTreeMap<u32, Node>,
+    edges: BTreeMap<(u32, u32), u32>,
+}
+
+impl Graph {
+    fn from_nodes(node_list: Vec<Node>) -> Self {
+        let (nodes, edges) = Self::build_graph(node_list);
+
+        Self { nodes, edges }
+    }
+
+    fn build_graph(node_list: Vec<Node>) -> (BTreeMap<u32, Node>, BTreeMap<(u32, u32), u32>) {
+        let mut nodes = BTreeMap::new();
+        let mut edges = BTreeMap::new();
+
+        for node in node_list {
+            for neighbour_id in &node.neighbours {
+                edges.insert(Self::edge_id(node.id, *neighbour_id), 1);
+            }
+
+            nodes.insert(node.id, node);
+        }
+
+        (nodes, edges)
+    }
+
+    fn edge_id(node_a: u32, node_b: u32) -> (u32, u32) {
+        if node_a < node_b {
+            (node_a, node_b)
+        } else {
+            (node_b, node_a)
+        }
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut lines = Vec::new();
+
+        lines.push(String::from("graph {"));
+
+        for node in self.nodes.values() {
+            lines.push(format!(
+                "  {0} [ label = \"{0} {1}\" ];",
+                fmt_node_id(node.id),
+                node.flow_rate
+            ));
+        }
+
+        lines.push(String::new());
+
+        for ((node_a_id, node_b_id), cost) in &self.edges {
+            lines.push(format!(
+                "  {} -- {} [ label = \"{}\" ];",
+                fmt_node_id(*node_a_id),
+                fmt_node_id(*node_b_id),
+                cost
+            ));
+        }
+
+        lines.push(String::from("}"));
+
+        lines.join("\n")
+    }
+
+    fn simplify(&mut self) {
+        let root_id = parse_node_id("AA");
+        let removable_node_ids: Vec<u32> = self
+            .nodes
+            .values()
+            .filter(|node| node.flow_rate == 0 && node.id != root_id)
+            .map(|node| node.id)
+            .collect();
+
+        for node_id in removable_node_ids {
+            let node = self.nodes.remove(&node_id).unwrap();
+
+            for neighbour_id in &node.neighbours {
+                self.nodes
+                    .get_mut(neighbour_id)
+                    .unwrap()
+                    .neighbours
+                    .remove(&node_id);
+
+                for new_neighbour_id in &node.neighbours {
+                    if neighbour_id != new_neighbour_id {
+                        self.nodes
+                            .get_mut(neighbour_id)
+                            .unwrap()
+                            .neighbours
+                            .insert(*new_neighbour_id);
+                    }
+                }
+
+                if let Some(cost) = self.edges.remove(&Self::edge_id(*neighbour_id, node_id)) {
+                    for new_neighbour_id in &node.neighbours {
+                        if neighbour_id != new_neighbour_id {
+                            let edge_id = Self::edge_id(*neighbour_id, *new_neighbour_id);
+                            if self.edges.contains_key(&edge_id) {
+                                self.edges.insert(edge_id, cost + self.edges[&edge_id] - 1);
+                            } else {
+                                self.edges.insert(edge_id, cost + 1);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn distance(&self, node_a_id: u32, node_b_id: u32) -> u32 {
+        let mut queue = BTreeSet::new();
+        let mut distances = BTreeMap::new();
+
+        for node_id in self.nodes.keys() {
+            distances.insert(node_id, u32::MAX);
+            queue.insert(node_id);
+        }
+
+        distances.insert(&node_a_id, 0);
+
+        while !queue.is_empty() {
+            let node_id = *queue
+                .iter()
+                .map(|node_id| (node_id, distances[node_id]))
+                .reduce(|(nearest_node_id, nearest_distance), (node_id, distance)| {
+                    if distance < nearest_distance {
+                        (node_id, distance)
+                    } else {
+                        (nearest_node_id, nearest_distance)
+                    }
+                })
+                .unwrap()
+                .0;
+
+            if *node_id == node_b_id {
+                return distances[node_id];
+            }
+
+            queue.remove(node_id);
+
+            let to_visit: Vec<&u32> = self
+                .nodes
+                .get(node_id)
+                .unwrap()
+                .neighbours
+                .iter()
+                .filter(|neighbour_id| queue.contains(neighbour_id))
+                .collect();
+
+            for neighbour_id in to_visit {
+                let new_distance =
+                    distances[node_id] + self.edges[&Self::edge_id(*node_id, *neighbour_id)];
+
+                if new_distance < distances[neighbour_id] {
+                    distances.insert(neighbour_id, new_distance);
+                }
+            }
+        }
+
+        u32::MAX
+    }
+}
+
+#[derive(Clone, Debug)]
+struct GameState<'a> {
+    rounds_left: u32,
+    visited_nodes: BTreeMap<u32, bool>, // Node -> opened
+    released_pressure: u32,
+    graph: &'a Graph,
+}
+
+impl<'a> GameState<'a> {
+    fn from(graph: &'a Graph) -> Self {
+        GameState {
+            rounds_left: 30,
+            visited_nodes: BTreeMap::new(),
+            released_pressure: 0,
+            graph,
+        }
+    }
+
+    fn current_flow_rate(&self) -> u32 {
+        self.visited_nodes
+            .iter()
+            .map(|(node_id, opened)| {
+                if *opened {
+                    self.graph.nodes[node_id].flow_rate
+                } else {
+                    0
+                }
+            })
+            .sum::<u32>()
+    }
+
+    fn tick(&mut self, rounds: u32) {
+        self.rounds_left -= rounds;
+        self.released_pressure += self.current_flow_rate() * rounds;
+    }
+
+    fn score(&self) -> u32 {
+        self.released_pressure + self.rounds_left * self.current_flow_rate()
+    }
+}
+
+fn walk<'a>(
+    node_id: u32,
+    distances: &'a BTreeMap<(u32, u32), u32>,
+    game_state: GameState<'a>,
+    path: Vec<String>,
+) -> GameState<'a> {
+    let mut path = path;
+    path.push(fmt_node_id(node_id));
+    if game_state.rounds_left == 0 {
+        // println!("{} times up", path.join(" -> "));
+        return game_state;
+    }
+
+    let best_game_state = game_state.clone();
+
+    let neighbour_ids: Vec<u32> = game_state
+        .graph
+        .nodes
+        .iter()
+        .filter(|(id, _)| {
+            *id != &node_id
+                && (!game_state.visited_nodes.contains_key(id))
+        })
+        .map(|(id, _)| *id)
+        .collect();
+
+    let mut right_game_state = game_state.clone();
+    if node_id != parse_node_id("AA") {
+        right_game_state.tick(1);
+    }
+    right_game_state.visited_nodes.insert(node_id, true);
+
+    let mut new_best_game_state = if right_game_state.score() >= best_game_state.score() {
+        right_game_state.clone()
+    } else {
+        best_game_state.clone()
+    };
+
+    for neighbour_id in neighbour_ids {
+        let distance = distances[&Graph::edge_id(node_id, neighbour_id)];
+        if distance <= right_game_state.rounds_left {
+            let mut new_game_state = right_game_state.clone();
+            new_game_state.tick(distance);
+
+            let new_game_state = walk(neighbour_id, distances, new_game_state, path.clone());
+
+            if new_game_state.score() > new_best_game_state.score() {
+                new_best_game_state = new_game_state;
+            }
+        }
+    }
+
+    // println!("{} graph done {} - {} - {}", path.join(" -> "), best_game_state.score(), best_game_state.current_flow_rate(), best_game_state.rounds_left);
+    new_best_game_state
+}
+
+pub fn parse(data: &str) -> Result<Graph, ParseError> {
+    let nodes = data.lines().map(parse_line).collect::<Result<Vec<_>, _>>()?;
+
+    if !nodes.iter().any(|node| node.id == parse_node_id("AA")) {
+        return Err(ParseError::after(data, "a valve AA"));
+    }
+
+    let mut graph = Graph::from_nodes(nodes);
+    graph.simplify();
+
+    Ok(graph)
+}
+
+pub fn part_one(graph: &Graph) -> u32 {
+    let distances = calculate_distances(graph);
+
+    let game_state = walk(parse_node_id("AA"), &distances, GameState::from(graph), Vec::new());
+
+    game_state.score()
+}
+
+/// Finds the most pressure one walker releases in `minutes` for every set of
+/// opened valves, indexed by a bitmask of the valves with a flow rate.
+fn best_per_subset(graph: &Graph, minutes: u32) -> Vec<u32> {
+    let distances = calculate_distances(graph);
+    let start_id = parse_node_id("AA");
+    let valves: Vec<&Node> = graph.nodes.values().filter(|node| node.flow_rate > 0).collect();
+
+    // A distance matrix over the valves, with the start as the last row.
+    let node_ids: Vec<u32> = valves.iter().map(|node| node.id).chain([start_id]).collect();
+    let matrix: Vec<Vec<u32>> = node_ids
+        .iter()
+        .map(|&node_a_id| {
+            valves
+                .iter()
+                .map(|node_b| {
+                    if node_a_id == node_b.id {
+                        0
+                    } else {
+                        distances[&Graph::edge_id(node_a_id, node_b.id)]
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let flow_rates: Vec<u32> = valves.iter().map(|node| node.flow_rate).collect();
+    let mut best = vec![0; 1 << valves.len()];
+
+    open_valves(valves.len(), minutes, 0, 0, &matrix, &flow_rates, &mut best);
+
+    best
+}
+
+fn open_valves(
+    position: usize,
+    minutes_left: u32,
+    opened: usize,
+    released_pressure: u32,
+    matrix: &[Vec<u32>],
+    flow_rates: &[u32],
+    best: &mut [u32],
+) {
+    best[opened] = best[opened].max(released_pressure);
+
+    for (valve, &flow_rate) in flow_rates.iter().enumerate() {
+        // Walking there and opening the valve has to leave time for it to release anything.
+        let cost = matrix[position][valve] + 1;
+
+        if opened & (1 << valve) == 0 && cost < minutes_left {
+            let minutes_left = minutes_left - cost;
+
+            open_valves(
+                valve,
+                minutes_left,
+                opened | (1 << valve),
+                released_pressure + flow_rate * minutes_left,
+                matrix,
+                flow_rates,
+                best,
+            );
+        }
+    }
+}
+
+pub fn part_two(graph: &Graph) -> u32 {
+    let best = best_per_subset(graph, 26);
+    let all_valves = best.len() - 1;
+
+    // The best of every subset of a set, so the elephant can take any of the valves you leave.
+    let mut best_within = best.clone();
+    for bit in (0..).map(|valve| 1 << valve).take_while(|&bit| bit < best.len()) {
+        for opened in 0..best.len() {
+            if opened & bit != 0 {
+                best_within[opened] = best_within[opened].max(best_within[opened ^ bit]);
+            }
+        }
+    }
+
+    (0..best.len())
+        .map(|opened| best[opened] + best_within[all_valves ^ opened])
+        .max()
+        .unwrap_or(0)
+}
+
+fn calculate_distances(graph: &Graph) -> BTreeMap<(u32, u32), u32> {
+    let node_ids: Vec<u32> = graph.nodes.keys().map(|x| x.to_owned()).collect();
+    let mut distances = BTreeMap::new();
+
+    for (i, &node_a_id) in node_ids.iter().enumerate() {
+        for &node_b_id in &node_ids[i + 1..] {
+            distances.insert(
+                Graph::edge_id(node_a_id, node_b_id),
+                graph.distance(node_a_id, node_b_id),
+            );
+        }
+    }
+    distances
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Graph;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::input::{inputs_dir, Source};
+    use std::fs;
+
+    impl Graph {
+        fn from_file(file: &str) -> Self {
+            let data = fs::read_to_string(file).expect("Can't read input file");
+
+            Self::from_nodes(data.lines().map(|line| parse_line(line).unwrap()).collect())
+        }
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let line = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB";
+        let node_data = parse_line(line).unwrap();
+        let expected_node_data = Node {
+            id: 370,
+            flow_rate: 0,
+            neighbours: BTreeSet::from_iter(vec![481, 666, 407]),
+        };
+
+        assert_eq!(expected_node_data, node_data);
+    }
+
+    #[test]
+    fn test_simplify() {
+        let mut graph = Graph::from_file("fixtures/example.txt");
+        graph.simplify();
+
+        for node in graph.nodes.values() {
+            for neighbour_id in &node.neighbours {
+                assert!(graph
+                    .edges
+                    .contains_key(&Graph::edge_id(node.id, *neighbour_id)));
+            }
+        }
+
+        for (left, right) in graph.edges.keys() {
+            assert!(graph.nodes.contains_key(left));
+            assert!(graph.nodes.contains_key(right));
+        }
+    }
+
+    #[test]
+    fn test_distance_small() {
+        let mut graph = Graph::from_file("fixtures/example.txt");
+        graph.simplify();
+
+        assert_eq!(0, graph.distance(parse_node_id("AA"), parse_node_id("AA")));
+        assert_eq!(2, graph.distance(parse_node_id("AA"), parse_node_id("JJ")));
+        assert_eq!(5, graph.distance(parse_node_id("AA"), parse_node_id("HH")));
+    }
+
+    #[test]
+    fn test_distance() {
+        // Pinned to the real puzzle input, so it only runs when that is available.
+        let Ok(data) = Source::for_day(&inputs_dir(), 2022, 16).read() else {
+            return;
+        };
+        let mut graph = Graph::from_nodes(data.lines().map(|line| parse_line(line).unwrap()).collect());
+        graph.simplify();
+
+        assert_eq!(0, graph.distance(parse_node_id("AA"), parse_node_id("AA")));
+        assert_eq!(2, graph.distance(parse_node_id("AA"), parse_node_id("IF")));
+        assert_eq!(3, graph.distance(parse_node_id("AA"), parse_node_id("MH")));
+        assert_eq!(5, graph.distance(parse_node_id("AA"), parse_node_id("MU")));
+    }
+
+    #[test]
+    fn test_walk() {
+        let lines = vec![
+            "Valve AA has flow rate=0; tunnels lead to valves BB",
+            "Valve BB has flow rate=13; tunnels lead to valves AA, CC",
+            "Valve CC has flow rate=2; tunnels lead to valves BB",
+        ];
+        let node_list: Vec<Node> = lines.into_iter().map(|line| parse_line(line).unwrap()).collect();
+        let graph = Graph::from_nodes(node_list);
+
+        let distances = calculate_distances(&graph);
+
+        let game_state = walk(parse_node_id("AA"), &distances, GameState::from(&graph), Vec::new());
+
+        assert_eq!(28 * 13 + 26 * 2, game_state.score());
+    }
+
+    #[test]
+    fn test_part_one_small() {
+        let mut graph = Graph::from_file("fixtures/example.txt");
+        graph.simplify();
+
+        assert_eq!(1651, part_one(&graph));
+    }
+
+    #[test]
+    fn test_part_two_small() {
+        let mut graph = Graph::from_file("fixtures/example.txt");
+        graph.simplify();
+
+        assert_eq!(1707, part_two(&graph));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day16>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,226 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+/// Every row of the chamber is a bitmask, with the leftmost of the seven
+/// columns in bit 6.
+const FULL_ROW: u8 = 0b1111111;
+const LEFT_WALL: u8 = 0b1000000;
+const RIGHT_WALL: u8 = 0b0000001;
+
+/// The rocks in falling order, bottom row first and already two units away
+/// from the left wall. Unused rows are empty.
+const ROCKS: [[u8; 4]; 5] = [
+    [0b0011110, 0, 0, 0],
+    [0b0001000, 0b0011100, 0b0001000, 0],
+    [0b0011100, 0b0000100, 0b0000100, 0],
+    [0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    [0b0011000, 0b0011000, 0, 0],
+];
+
+#[derive(Clone, Copy)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+/// Moves `rock` one unit sideways, unless that would take it into a wall.
+fn push(rock: &[u8; 4], jet: Jet) -> Option<[u8; 4]> {
+    match jet {
+        Jet::Left if rock.iter().all(|row| row & LEFT_WALL == 0) => Some(rock.map(|row| row << 1)),
+        Jet::Right if rock.iter().all(|row| row & RIGHT_WALL == 0) => {
+            Some(rock.map(|row| row >> 1))
+        }
+        _ => None,
+    }
+}
+
+struct Chamber<'a> {
+    jets: &'a [Jet],
+    rows: Vec<u8>,
+    next_jet: usize,
+    dropped: usize,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [Jet]) -> Self {
+        Self {
+            jets,
+            rows: Vec::new(),
+            next_jet: 0,
+            dropped: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn collides(&self, rock: &[u8; 4], y: usize) -> bool {
+        rock.iter()
+            .zip(self.rows.iter().skip(y))
+            .any(|(row, settled)| row & settled != 0)
+    }
+
+    fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.dropped % ROCKS.len()];
+        let mut y = self.height() + 3;
+
+        loop {
+            let jet = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+
+            if let Some(pushed) = push(&rock, jet) {
+                if !self.collides(&pushed, y) {
+                    rock = pushed;
+                }
+            }
+
+            if y == 0 || self.collides(&rock, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for (dy, row) in rock.iter().enumerate().filter(|(_, row)| **row != 0) {
+            match self.rows.get_mut(y + dy) {
+                Some(settled) => *settled |= row,
+                None => self.rows.push(*row),
+            }
+        }
+
+        self.dropped += 1;
+    }
+
+    /// The rows from the top down to the lowest one a falling rock could
+    /// still reach. Rocks only move sideways and down, so whatever lies
+    /// below can't make a difference any more, and two chambers with the
+    /// same surface grow the same way from here on.
+    fn surface(&self) -> &[u8] {
+        let mut reachable = FULL_ROW;
+        let mut lowest = self.height();
+
+        for y in (0..self.height()).rev() {
+            let free = !self.rows[y] & FULL_ROW;
+            let mut row = free & reachable;
+
+            loop {
+                let spread = (row | row << 1 | row >> 1) & free;
+                if spread == row {
+                    break;
+                }
+                row = spread;
+            }
+
+            if row == 0 {
+                break;
+            }
+            reachable = row;
+            lowest = y;
+        }
+
+        &self.rows[lowest..]
+    }
+}
+
+/// The height of the tower after `rocks` rocks have come to rest.
+///
+/// Once the next rock, the next jet and the surface repeat, so does
+/// everything after, so the rest of the rocks are skipped a whole cycle at
+/// a time. The states are compared exactly rather than by a hash of them.
+pub fn tower_height(jets: &[Jet], rocks: usize) -> usize {
+    let mut chamber = Chamber::new(jets);
+    let mut heights = vec![0];
+    let mut seen: HashMap<(usize, usize, Vec<u8>), usize> = HashMap::new();
+
+    while chamber.dropped < rocks {
+        chamber.drop_rock();
+        heights.push(chamber.height());
+
+        let state = (
+            chamber.dropped % ROCKS.len(),
+            chamber.next_jet,
+            chamber.surface().to_vec(),
+        );
+
+        if let Some(&start) = seen.get(&state) {
+            let period = chamber.dropped - start;
+            let remaining = rocks - chamber.dropped;
+            let growth = chamber.height() - heights[start];
+            let rest = heights[start + remaining % period] - heights[start];
+
+            return chamber.height() + remaining / period * growth + rest;
+        }
+
+        seen.insert(state, chamber.dropped);
+    }
+
+    chamber.height()
+}
+
+pub fn parse(data: &str) -> Result<Vec<Jet>, ParseError> {
+    let pattern = data.trim_end();
+    if pattern.is_empty() {
+        return Err(ParseError::after(data, "a jet pattern"));
+    }
+
+    parse::check_chars(pattern, |c| c == '<' || c == '>', "'<' or '>'")?;
+
+    Ok(pattern
+        .chars()
+        .map(|c| if c == '<' { Jet::Left } else { Jet::Right })
+        .collect())
+}
+
+pub fn part_one(jets: &[Jet]) -> usize {
+    tower_height(jets, 2022)
+}
+
+pub fn part_two(jets: &[Jet]) -> usize {
+    tower_height(jets, 1_000_000_000_000)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Vec<Jet>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day17>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_skipping_cycles() {
+        let jets = parse(include_str!("../fixtures/example.txt")).unwrap();
+        let mut chamber = Chamber::new(&jets);
+
+        for rocks in 1..=3000 {
+            chamber.drop_rock();
+
+            if rocks % 250 == 0 {
+                assert_eq!(chamber.height(), tower_height(&jets, rocks));
+            }
+        }
+    }
+}
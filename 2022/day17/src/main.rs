@@ -0,0 +1,9 @@
+use aoc_common::input;
+use day17::{parse, part_one, part_two};
+
+fn main() {
+    let data = input::load_or_exit(2022, 17);
+    let jets = input::parse_or_exit(&data, parse);
+    println!("Part one: {}", part_one(&jets));
+    println!("Part two: {}", part_two(&jets));
+}
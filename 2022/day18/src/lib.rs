@@ -0,0 +1,86 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use aoc_grid::Point3;
+use aoc_search::bfs;
+use std::collections::HashSet;
+
+pub fn parse(data: &str) -> Result<HashSet<Point3>, ParseError> {
+    let cubes = data
+        .lines()
+        .map(Point3::parse)
+        .collect::<Result<HashSet<_>, _>>()?;
+
+    if cubes.is_empty() {
+        return Err(ParseError::after(data, "a cube"));
+    }
+
+    Ok(cubes)
+}
+
+pub fn part_one(cubes: &HashSet<Point3>) -> usize {
+    cubes
+        .iter()
+        .flat_map(|cube| cube.neighbors6())
+        .filter(|side| !cubes.contains(side))
+        .count()
+}
+
+/// Counts only the faces that steam can reach: the air around the droplet
+/// is flooded within a box one unit larger than the droplet on every side,
+/// and every face between that air and a cube is on the outside.
+pub fn part_two(cubes: &HashSet<Point3>) -> usize {
+    let (Some(min), Some(max)) = (
+        cubes.iter().copied().reduce(Point3::min_each),
+        cubes.iter().copied().reduce(Point3::max_each),
+    ) else {
+        return 0;
+    };
+
+    let corner = Point3::new(1, 1, 1);
+    let (min, max) = (min - corner, max + corner);
+
+    let in_box = |point: &Point3| point.min_each(min) == min && point.max_each(max) == max;
+    let outside = bfs([min], |air: &Point3| {
+        air.neighbors6()
+            .filter(|next| in_box(next) && !cubes.contains(next))
+            .collect::<Vec<_>>()
+    });
+
+    outside
+        .distances()
+        .keys()
+        .flat_map(|air| air.neighbors6())
+        .filter(|side| cubes.contains(side))
+        .count()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 18;
+
+    type Input<'a> = HashSet<Point3>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day18>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,9 @@
+use aoc_common::input;
+use day18::{parse, part_one, part_two};
+
+fn main() {
+    let data = input::load_or_exit(2022, 18);
+    let cubes = input::parse_or_exit(&data, parse);
+    println!("Part one: {}", part_one(&cubes));
+    println!("Part two: {}", part_two(&cubes));
+}
@@ -0,0 +1,194 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use rayon::prelude::*;
+
+/// Robots and resources share their indexes: ore, clay, obsidian, geode.
+const KINDS: [&str; 4] = ["ore", "clay", "obsidian", "geode"];
+const GEODE: usize = 3;
+
+pub struct Blueprint {
+    id: u32,
+    /// What each robot costs, by resource. Nothing costs geodes.
+    costs: [[u32; 3]; 4],
+    /// The most of each resource any robot costs. There's no point in
+    /// having more robots collecting it than can be spent in a minute.
+    max_costs: [u32; 3],
+}
+
+#[derive(Clone, Copy)]
+struct State {
+    minutes_left: u32,
+    robots: [u32; 4],
+    resources: [u32; 4],
+}
+
+impl Blueprint {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let (header, rules) = parse::split_once(line, ": ")?;
+        let id = parse::number(parse::strip_prefix(header, "Blueprint ")?)?;
+
+        let sentences = parse::split_n::<4>(parse::strip_suffix(rules, ".")?, ". ")?;
+        let mut costs = [[0; 3]; 4];
+
+        for ((sentence, kind), cost) in sentences.iter().zip(KINDS).zip(&mut costs) {
+            let items = parse::strip_prefix(sentence, &format!("Each {} robot costs ", kind))?;
+
+            for item in items.split(" and ") {
+                let [amount, resource] = parse::words::<2>(item)?;
+                let index = KINDS[..GEODE]
+                    .iter()
+                    .position(|&kind| kind == resource)
+                    .ok_or_else(|| ParseError::new(resource, "ore, clay or obsidian"))?;
+
+                cost[index] = parse::number(amount)?;
+            }
+        }
+
+        let mut max_costs = [0; 3];
+        for cost in &costs {
+            for (max, &amount) in max_costs.iter_mut().zip(cost) {
+                *max = (*max).max(amount);
+            }
+        }
+
+        Ok(Self {
+            id,
+            costs,
+            max_costs,
+        })
+    }
+
+    /// The most geodes that can be open after `minutes`, starting with a
+    /// single ore robot.
+    pub fn max_geodes(&self, minutes: u32) -> u32 {
+        let start = State {
+            minutes_left: minutes,
+            robots: [1, 0, 0, 0],
+            resources: [0; 4],
+        };
+        let mut best = 0;
+
+        self.search(start, &mut best);
+
+        best
+    }
+
+    /// Instead of deciding minute by minute, picks the next robot to build
+    /// and skips ahead to the minute it's done.
+    fn search(&self, state: State, best: &mut u32) {
+        let minutes = state.minutes_left;
+        let geodes = state.resources[GEODE] + state.robots[GEODE] * minutes;
+        *best = (*best).max(geodes);
+
+        // Even with a new geode robot every minute from now on, this branch
+        // can't beat the best so far.
+        if geodes + minutes * minutes.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+
+        for robot in (0..KINDS.len()).rev() {
+            if robot != GEODE && state.robots[robot] >= self.max_costs[robot] {
+                continue;
+            }
+
+            let Some(wait) = self.minutes_until_affordable(&state, robot) else {
+                continue;
+            };
+
+            // A robot finished in the last minute doesn't collect anything.
+            if wait + 1 >= minutes {
+                continue;
+            }
+
+            let mut next = state;
+            next.minutes_left -= wait + 1;
+            for (resource, robots) in next.resources.iter_mut().zip(state.robots) {
+                *resource += robots * (wait + 1);
+            }
+            for (resource, cost) in next.resources.iter_mut().zip(self.costs[robot]) {
+                *resource -= cost;
+            }
+            next.robots[robot] += 1;
+
+            self.search(next, best);
+        }
+    }
+
+    /// `None` if no robot collects a resource the robot needs.
+    fn minutes_until_affordable(&self, state: &State, robot: usize) -> Option<u32> {
+        let mut wait = 0;
+
+        for (resource, &cost) in self.costs[robot].iter().enumerate() {
+            let missing = cost.saturating_sub(state.resources[resource]);
+
+            if missing > 0 {
+                let robots = state.robots[resource];
+                if robots == 0 {
+                    return None;
+                }
+                wait = wait.max(missing.div_ceil(robots));
+            }
+        }
+
+        Some(wait)
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let blueprints = data
+        .lines()
+        .map(Blueprint::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if blueprints.is_empty() {
+        return Err(ParseError::after(data, "a blueprint"));
+    }
+
+    Ok(blueprints)
+}
+
+pub fn part_one(blueprints: &[Blueprint]) -> u32 {
+    blueprints
+        .par_iter()
+        .map(|blueprint| blueprint.id * blueprint.max_geodes(24))
+        .sum()
+}
+
+pub fn part_two(blueprints: &[Blueprint]) -> u32 {
+    blueprints
+        .par_iter()
+        .take(3)
+        .map(|blueprint| blueprint.max_geodes(32))
+        .product()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 19;
+
+    type Input<'a> = Vec<Blueprint>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day19>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Clone, Copy)]
+enum Rps {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Clone, Copy)]
+enum RoundResult {
+    Loose,
+    Draw,
+    Win,
+}
+
+pub struct Round {
+    opponent_move: Rps,
+    second_column: char,
+}
+
+impl Round {
+    fn player_move(&self) -> Rps {
+        match self.second_column {
+            'X' => Rps::Rock,
+            'Y' => Rps::Paper,
+            'Z' => Rps::Scissors,
+            _ => panic!("unknown move"),
+        }
+    }
+
+    fn expected_result(&self) -> RoundResult {
+        match self.second_column {
+            'X' => RoundResult::Loose,
+            'Y' => RoundResult::Draw,
+            'Z' => RoundResult::Win,
+            _ => panic!("unknown round result"),
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Result<Round, ParseError> {
+    let [first_column, second_column] = parse::split_n(line, " ")?;
+
+    let opponent_move = match first_column {
+        "A" => Rps::Rock,
+        "B" => Rps::Paper,
+        "C" => Rps::Scissors,
+        _ => return Err(ParseError::new(first_column, "'A', 'B' or 'C'")),
+    };
+
+    let second_column = match second_column {
+        "X" | "Y" | "Z" => second_column.chars().next().unwrap(),
+        _ => return Err(ParseError::new(second_column, "'X', 'Y' or 'Z'")),
+    };
+
+    Ok(Round {
+        opponent_move,
+        second_column,
+    })
+}
+
+fn points_for_move(player_move: Rps) -> u32 {
+    match player_move {
+        Rps::Rock => 1,
+        Rps::Paper => 2,
+        Rps::Scissors => 3,
+    }
+}
+
+fn points_for_result(result: RoundResult) -> u32 {
+    match result {
+        RoundResult::Loose => 0,
+        RoundResult::Draw => 3,
+        RoundResult::Win => 6,
+    }
+}
+
+fn play(opponent_move: Rps, player_move: Rps) -> RoundResult {
+    match (opponent_move, player_move) {
+        (Rps::Rock, Rps::Paper) | (Rps::Paper, Rps::Scissors) | (Rps::Scissors, Rps::Rock) => {
+            RoundResult::Win
+        }
+        (Rps::Rock, Rps::Rock) | (Rps::Paper, Rps::Paper) | (Rps::Scissors, Rps::Scissors) => {
+            RoundResult::Draw
+        }
+        _ => RoundResult::Loose,
+    }
+}
+
+fn move_for_result(opponent_move: Rps, result: RoundResult) -> Rps {
+    match (opponent_move, result) {
+        (Rps::Rock, RoundResult::Loose) => Rps::Scissors,
+        (Rps::Rock, RoundResult::Draw) => Rps::Rock,
+        (Rps::Rock, RoundResult::Win) => Rps::Paper,
+
+        (Rps::Paper, RoundResult::Loose) => Rps::Rock,
+        (Rps::Paper, RoundResult::Draw) => Rps::Paper,
+        (Rps::Paper, RoundResult::Win) => Rps::Scissors,
+
+        (Rps::Scissors, RoundResult::Loose) => Rps::Paper,
+        (Rps::Scissors, RoundResult::Draw) => Rps::Scissors,
+        (Rps::Scissors, RoundResult::Win) => Rps::Rock,
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Round>, ParseError> {
+    data.lines().map(parse_line).collect()
+}
+
+pub fn part_one(rounds: &[Round]) -> u32 {
+    rounds
+        .iter()
+        .map(|round| {
+            let player_move = round.player_move();
+            points_for_result(play(round.opponent_move, player_move)) + points_for_move(player_move)
+        })
+        .sum()
+}
+
+pub fn part_two(rounds: &[Round]) -> u32 {
+    rounds
+        .iter()
+        .map(|round| {
+            let result = round.expected_result();
+            points_for_result(result) + points_for_move(move_for_result(round.opponent_move, result))
+        })
+        .sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Round>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,232 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+const DECRYPTION_KEY: i64 = 811_589_153;
+
+/// The numbers in their current circular order, by their original index.
+///
+/// The order is split into blocks of about the square root of the length,
+/// and every number knows its block, so finding, removing and inserting a
+/// number only scans one block and the list of block lengths.
+struct Ring {
+    blocks: Vec<Vec<usize>>,
+    block_of: Vec<usize>,
+    block_size: usize,
+}
+
+impl Ring {
+    fn new(len: usize) -> Self {
+        let block_size = (len as f64).sqrt().ceil().max(1.0) as usize;
+        let mut ring = Self {
+            blocks: vec![(0..len).collect()],
+            block_of: vec![0; len],
+            block_size,
+        };
+
+        ring.rebalance();
+        ring
+    }
+
+    fn len(&self) -> usize {
+        self.block_of.len()
+    }
+
+    /// Cuts the order into equal blocks again once one has grown too big.
+    fn rebalance(&mut self) {
+        let order = self.order();
+
+        self.blocks = order
+            .chunks(self.block_size)
+            .map(|chunk| chunk.to_vec())
+            .collect();
+
+        for (block, indexes) in self.blocks.iter().enumerate() {
+            for &index in indexes {
+                self.block_of[index] = block;
+            }
+        }
+    }
+
+    fn order(&self) -> Vec<usize> {
+        self.blocks.concat()
+    }
+
+    /// Takes the number out of the ring and returns where it was.
+    fn remove(&mut self, index: usize) -> usize {
+        let block = self.block_of[index];
+        let before: usize = self.blocks[..block].iter().map(Vec::len).sum();
+        let offset = self.blocks[block]
+            .iter()
+            .position(|&other| other == index)
+            .unwrap();
+
+        self.blocks[block].remove(offset);
+
+        before + offset
+    }
+
+    fn insert(&mut self, position: usize, index: usize) {
+        let mut offset = position;
+        let mut block = 0;
+
+        while block + 1 < self.blocks.len() && offset > self.blocks[block].len() {
+            offset -= self.blocks[block].len();
+            block += 1;
+        }
+
+        self.blocks[block].insert(offset, index);
+        self.block_of[index] = block;
+
+        if self.blocks[block].len() > 2 * self.block_size {
+            self.rebalance();
+        }
+    }
+}
+
+/// Moves every number, in the original order, as many places forward or
+/// back as its value, `rounds` times over, and returns the final order.
+fn mix(numbers: &[i64], rounds: usize) -> Vec<i64> {
+    let mut ring = Ring::new(numbers.len());
+
+    // With one number taken out, the others form a ring of `len - 1`.
+    let others = ring.len() as i64 - 1;
+
+    if others > 0 {
+        for _ in 0..rounds {
+            for (index, &number) in numbers.iter().enumerate() {
+                let position = ring.remove(index) as i64;
+                ring.insert((position + number).rem_euclid(others) as usize, index);
+            }
+        }
+    }
+
+    ring.order()
+        .into_iter()
+        .map(|index| numbers[index])
+        .collect()
+}
+
+fn grove_coordinates(mixed: &[i64]) -> i64 {
+    let zero = mixed.iter().position(|&number| number == 0).unwrap();
+
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| mixed[(zero + offset) % mixed.len()])
+        .sum()
+}
+
+/// The numbers, with exactly one 0 to count the grove coordinates from.
+pub fn parse(data: &str) -> Result<Vec<i64>, ParseError> {
+    let mut numbers = Vec::new();
+    let mut zero = false;
+
+    for line in data.lines() {
+        let number = parse::number(line)?;
+
+        if number == 0 {
+            if zero {
+                return Err(ParseError::new(line, "only one 0"));
+            }
+            zero = true;
+        }
+
+        numbers.push(number);
+    }
+
+    if !zero {
+        return Err(ParseError::after(data, "a 0"));
+    }
+
+    Ok(numbers)
+}
+
+pub fn part_one(numbers: &[i64]) -> i64 {
+    grove_coordinates(&mix(numbers, 1))
+}
+
+pub fn part_two(numbers: &[i64]) -> i64 {
+    let decrypted: Vec<i64> = numbers
+        .iter()
+        .map(|number| number * DECRYPTION_KEY)
+        .collect();
+
+    grove_coordinates(&mix(&decrypted, 10))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 20;
+
+    type Input<'a> = Vec<i64>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The plain `Vec::remove` and `Vec::insert` mix to check against.
+    fn mix_slowly(numbers: &[i64], rounds: usize) -> Vec<i64> {
+        let mut order: Vec<usize> = (0..numbers.len()).collect();
+        let others = numbers.len() as i64 - 1;
+
+        for _ in 0..rounds {
+            for (index, &number) in numbers.iter().enumerate() {
+                if others > 0 {
+                    let position = order.iter().position(|&other| other == index).unwrap();
+                    order.remove(position);
+                    let position = (position as i64 + number).rem_euclid(others);
+                    order.insert(position as usize, index);
+                }
+            }
+        }
+
+        order.into_iter().map(|index| numbers[index]).collect()
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day20>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let cases: [&[i64]; 6] = [
+            &[0],
+            &[0, 5],
+            &[0, 3, 3, -3, 3],
+            &[4, 0, 4, -4, 4, 8],
+            &[-1, -1, 1, 0, 1, 7, -7, 1],
+            &[2, 2, 2, 2, 2, 0, 2, 2, 2, 2, 2, 2, -2, -2],
+        ];
+
+        for numbers in cases {
+            assert_eq!(mix_slowly(numbers, 1), mix(numbers, 1), "{:?}", numbers);
+            assert_eq!(mix_slowly(numbers, 10), mix(numbers, 10), "{:?}", numbers);
+        }
+    }
+
+    #[test]
+    fn test_long_list() {
+        let numbers: Vec<i64> = (0..500)
+            .map(|index: i64| {
+                (index * 7919 % 211 - 105) * if index % 3 == 0 { DECRYPTION_KEY } else { 1 }
+            })
+            .collect();
+
+        assert_eq!(mix_slowly(&numbers, 10), mix(&numbers, 10));
+    }
+}
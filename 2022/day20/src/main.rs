@@ -0,0 +1,9 @@
+use aoc_common::input;
+use day20::{parse, part_one, part_two};
+
+fn main() {
+    let data = input::load_or_exit(2022, 20);
+    let numbers = input::parse_or_exit(&data, parse);
+    println!("Part one: {}", part_one(&numbers));
+    println!("Part two: {}", part_two(&numbers));
+}
@@ -0,0 +1,89 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+fn item_to_priority(item: char) -> u32 {
+    match item {
+        'a'..='z' => item as u32 - 96,
+        'A'..='Z' => item as u32 - 64 + 26,
+        _ => 0
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<&str>, ParseError> {
+    data.lines()
+        .map(|line| {
+            parse::check_chars(line, |c| c.is_ascii_alphabetic(), "an item letter")?;
+            if line.len() % 2 != 0 {
+                return Err(ParseError::after(line, "an even number of items"));
+            }
+
+            Ok(line)
+        })
+        .collect()
+}
+
+pub fn part1(rucksacks: &[&str]) -> u32 {
+    rucksacks.iter()
+        .map(|line| {
+            let (left, right): (&str, &str) = line.split_at(line.len() / 2);
+            let left: HashSet<char> = HashSet::from_iter(left.chars());
+            let right: HashSet<char> = HashSet::from_iter(right.chars());
+
+            let intersect = left.intersection(&right);
+
+            intersect.map(|c| item_to_priority(*c)).sum::<u32>()
+        }).sum()
+}
+
+pub fn part2(rucksacks: &[&str]) -> u32 {
+    rucksacks.iter()
+        .map(|line| HashSet::from_iter(line.chars()))
+        .collect::<Vec<HashSet<char>>>()
+        .chunks(3)
+        .map(|group| {
+            if group.is_empty() {
+                return 0;
+            }
+            let mut group = Vec::from(group);
+            let mut result_set = group.pop().unwrap();
+            for line in group {
+                result_set = result_set.intersection(&line)
+                    .copied()
+                    .collect();
+            }
+
+            result_set.iter().map(|c| item_to_priority(*c)).sum()
+        }).sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day03>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+type Pair = (u32, u32);
+type Group = (Pair, Pair);
+
+fn parse_item(item: &str) -> Result<Pair, ParseError> {
+    let (start, end) = parse::split_once(item, "-")?;
+
+    Ok((
+        parse::number(start)?,
+        parse::number(end)?
+    ))
+}
+
+fn parse_line(line: &str) -> Result<Group, ParseError> {
+    let (first, second) = parse::split_once(line, ",")?;
+
+    Ok((parse_item(first)?, parse_item(second)?))
+}
+
+fn check_contains(group: &Group) -> bool {
+    group.0.0 >= group.1.0 && group.0.1 <= group.1.1
+        || group.0.0 <= group.1.0 && group.0.1 >= group.1.1
+}
+
+fn check_overlap(group: &Group) -> bool {
+    group.0.1 >= group.1.0 && group.0.0 <= group.1.1
+        || group.0.1 <= group.1.0 && group.0.0 >= group.1.1
+}
+
+pub fn parse(data: &str) -> Result<Vec<Group>, ParseError> {
+    data.lines()
+        .map(parse_line)
+        .collect()
+}
+
+pub fn part_one(groups: &[Group]) -> usize {
+    groups.iter()
+        .filter(|&group| check_contains(group))
+        .count()
+}
+
+pub fn part_two(groups: &[Group]) -> usize {
+    groups.iter()
+        .filter(|&group| check_overlap(group))
+        .count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Group>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
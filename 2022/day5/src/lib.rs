@@ -0,0 +1,154 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+struct Action {
+    number_of_crates: usize,
+    source_stack: usize,
+    target_stack: usize,
+}
+
+pub struct Supplies {
+    stacks: Vec<Vec<char>>,
+    actions: Vec<Action>,
+}
+
+fn parse_header(data: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut header = data.lines().rev();
+
+    let stack_ids = header.next().ok_or_else(|| ParseError::after(data, "the stack numbers"))?;
+    let mut stacks: Vec<Vec<char>> = stack_ids.split_whitespace().map(|_| Vec::new()).collect();
+
+    for line in header {
+        for (index, crate_tag) in line.match_indices(char::is_alphabetic) {
+            let stack = (index > 0).then(|| (index - 1) / 4).and_then(|i| stacks.get_mut(i));
+            match stack {
+                Some(stack) => stack.push(crate_tag.chars().next().unwrap()),
+                None => return Err(ParseError::new(crate_tag, "a crate above a stack number")),
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+fn parse_stack(token: &str, number_of_stacks: usize) -> Result<usize, ParseError> {
+    match parse::number::<usize>(token)? {
+        number @ 1.. if number <= number_of_stacks => Ok(number - 1),
+        _ => Err(ParseError::new(token, format!("a stack from 1 to {}", number_of_stacks))),
+    }
+}
+
+fn parse_actions(data: &str, number_of_stacks: usize) -> Result<Vec<Action>, ParseError> {
+    data.lines()
+        .map(|line| {
+            let ["move", number_of_crates, "from", source_stack, "to", target_stack] =
+                parse::words(line)?
+            else {
+                return Err(ParseError::new(line, "'move <n> from <stack> to <stack>'"));
+            };
+
+            Ok(Action {
+                number_of_crates: parse::number(number_of_crates)?,
+                source_stack: parse_stack(source_stack, number_of_stacks)?,
+                target_stack: parse_stack(target_stack, number_of_stacks)?,
+            })
+        })
+        .collect()
+}
+
+pub fn parse(data: &str) -> Result<Supplies, ParseError> {
+    let (header, actions) = parse::split_once(data, "\n\n")?;
+
+    let stacks = parse_header(header)?;
+    let actions = parse_actions(actions, stacks.len())?;
+
+    Ok(Supplies { stacks, actions })
+}
+
+fn create_result(stacks: &[Vec<char>]) -> String {
+    let mut result = String::new();
+
+    for stack in stacks {
+        result.push(match stack.last() {
+            Some(c) => *c,
+            None => ' ',
+        });
+    }
+
+    result
+}
+
+pub fn part_one(supplies: &Supplies) -> String {
+    let mut stacks = supplies.stacks.clone();
+
+    for action in &supplies.actions {
+        for _ in 0..action.number_of_crates {
+            let crate_tag = stacks[action.source_stack]
+                .pop()
+                .expect("Can't take from empty stack");
+            stacks[action.target_stack].push(crate_tag);
+        }
+    }
+
+    create_result(&stacks)
+}
+
+pub fn part_two(supplies: &Supplies) -> String {
+    let mut stacks = supplies.stacks.clone();
+
+    for action in &supplies.actions {
+        let split_off_index = stacks[action.source_stack].len() - action.number_of_crates;
+        let mut crate_tags = stacks[action.source_stack].split_off(split_off_index);
+        stacks[action.target_stack].append(&mut crate_tags);
+    }
+
+    create_result(&stacks)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Supplies;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part_one_small() {
+        let data = fs::read_to_string("fixtures/example.txt").expect("Can't read input file");
+        let result = part_one(&parse(&data).unwrap());
+
+        assert_eq!(result, "CMZ");
+    }
+
+    #[test]
+    fn test_part_two_small() {
+        let data = fs::read_to_string("fixtures/example.txt").expect("Can't read input file");
+        let result = part_two(&parse(&data).unwrap());
+
+        assert_eq!(result, "MCD");
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day05>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+fn find_start(data: &str, marker_size: usize) -> Option<usize> {
+    let mut char_set = HashSet::with_capacity(marker_size);
+
+    for index in marker_size..=data.len() {
+        let window = &data[index - marker_size..index];
+
+        for c in window.chars() {
+            char_set.insert(c);
+        }
+
+        if char_set.len() == marker_size {
+            return Some(index);
+        }
+
+        char_set.clear();
+    }
+
+    None
+}
+
+pub fn parse(data: &str) -> Result<&str, ParseError> {
+    parse::check_chars(data.trim_end(), |c| c.is_ascii_lowercase(), "a lowercase letter")
+}
+
+/// `None` if the signal has no start-of-packet marker.
+pub fn part_one(data: &str) -> Option<usize> {
+    find_start(data, 4)
+}
+
+/// `None` if the signal has no start-of-message marker.
+pub fn part_two(data: &str) -> Option<usize> {
+    find_start(data, 14)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type Input<'a> = &'a str;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_1() {
+        assert_eq!(find_start("bvwbjplbgvbhsrlpgdmjqwftvncz", 4).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_example_2() {
+        assert_eq!(find_start("nppdvjthqldpwncqszvftbrmjlhg", 4).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_example_3() {
+        assert_eq!(find_start("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_example_4() {
+        assert_eq!(find_start("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4).unwrap(), 11);
+    }
+
+    #[test]
+    fn test_example_5() {
+        assert_eq!(find_start("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14).unwrap(), 19);
+    }
+
+    #[test]
+    fn test_example_6() {
+        assert_eq!(find_start("bvwbjplbgvbhsrlpgdmjqwftvncz", 14).unwrap(), 23);
+    }
+
+    #[test]
+    fn test_example_7() {
+        assert_eq!(find_start("nppdvjthqldpwncqszvftbrmjlhg", 14).unwrap(), 23);
+    }
+
+    #[test]
+    fn test_example_8() {
+        assert_eq!(find_start("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14).unwrap(), 29);
+    }
+
+    #[test]
+    fn test_example_9() {
+        assert_eq!(find_start("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14).unwrap(), 26);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day06>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert_eq!(Some(7), part_one("mjqjpqmgbljsp"));
+        assert_eq!(None, part_two("mjqjpqmgbljsp"));
+        assert_eq!(None, part_one("aabbaabb"));
+    }
+}
@@ -0,0 +1,236 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::slice::Iter;
+
+#[derive(Debug, PartialEq)]
+enum Statement<'a> {
+    ChangeDir(&'a str),
+    List,
+    Directory(&'a str),
+    File(&'a str, usize),
+}
+
+struct File<'a> {
+    _name: &'a str,
+    size: usize,
+}
+
+pub struct Directory<'a> {
+    name: &'a str,
+    files: Vec<File<'a>>,
+    directories: Vec<Directory<'a>>,
+}
+
+impl<'a> Directory<'a> {
+    fn with_name(name: &'a str) -> Self {
+        Directory {
+            name,
+            files: Vec::new(),
+            directories: Vec::new(),
+        }
+    }
+
+    fn find_directory_index(&mut self, dir_name: &str) -> Option<usize> {
+        self.directories.iter().position(|dir| dir.name == dir_name)
+    }
+
+    fn add_directory(&mut self, directory: Directory<'a>) -> usize {
+        self.directories.push(directory);
+        self.directories.len() - 1
+    }
+
+    fn add_file(&mut self, file: File<'a>) {
+        self.files.push(file);
+    }
+
+    fn find_small_directories(
+        &'a self,
+        small_directories: &mut Vec<(&'a Directory<'a>, usize)>,
+        limit: usize,
+    ) -> usize {
+        let size = self.files.iter().map(|file| file.size).sum::<usize>()
+            + self
+                .directories
+                .iter()
+                .map(|directory| directory.find_small_directories(small_directories, limit))
+                .sum::<usize>();
+
+        if size <= limit {
+            small_directories.push((self, size));
+        }
+
+        size
+    }
+}
+
+fn parse_line(line: &str) -> Result<Statement<'_>, ParseError> {
+    let (first, rest) = parse::split_once(line, " ")?;
+
+    match first {
+        "$" => match rest.split_once(' ') {
+            Some(("cd", dir_name)) => Ok(Statement::ChangeDir(dir_name)),
+            None if rest == "ls" => Ok(Statement::List),
+            _ => Err(ParseError::new(rest, "'cd' or 'ls'")),
+        },
+        "dir" => Ok(Statement::Directory(rest)),
+        size_string => Ok(Statement::File(rest, parse::number(size_string)?)),
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Statement<'_>>, ParseError> {
+    input.lines().map(parse_line).collect()
+}
+
+fn execute_statements_on_dir<'a>(
+    directory: &mut Directory<'a>,
+    statement_iter: &mut Iter<Statement<'a>>,
+) {
+    while let Some(statement) = statement_iter.next() {
+        match statement {
+            Statement::List => {}
+            Statement::ChangeDir("..") => return,
+            Statement::ChangeDir(dir_name) => {
+                if let Some(index) = directory.find_directory_index(dir_name) {
+                    execute_statements_on_dir(&mut directory.directories[index], statement_iter)
+                }
+            }
+            Statement::Directory(name) => {
+                directory.add_directory(Directory::with_name(name));
+            }
+            Statement::File(name, size) => directory.add_file(File {
+                _name: name,
+                size: *size,
+            }),
+        }
+    }
+}
+
+fn create_filesystem<'a>(statements: Vec<Statement<'a>>) -> Directory<'a> {
+    let mut statement_iter = statements.iter();
+
+    statement_iter.next();
+
+    let mut root = Directory::with_name("/");
+
+    execute_statements_on_dir(&mut root, &mut statement_iter);
+
+    root
+}
+
+fn size_of_small_directories(directory: &Directory, limit: usize) -> Result<usize, &'static str> {
+    let mut small_directories = Vec::new();
+    directory.find_small_directories(&mut small_directories, limit);
+
+    Ok(small_directories.iter().map(|(_, size)| size).sum())
+}
+
+fn find_smallest_directory_to_delete<'a>(
+    directory: &'a Directory<'a>,
+    target: usize,
+) -> Option<usize> {
+    let mut directories = Vec::new();
+    directory.find_small_directories(&mut directories, usize::MAX);
+
+    directories.sort_by_key(|(_, size)| *size);
+    let root = directories.pop().unwrap();
+
+    directories
+        .iter()
+        .find(|(_, size)| root.1 - size <= target)
+        .map(|(_, size)| *size)
+}
+
+pub fn parse(data: &str) -> Result<Directory<'_>, ParseError> {
+    let statements = parse_input(data)?;
+
+    if statements.is_empty() {
+        return Err(ParseError::after(data, "a terminal line"));
+    }
+
+    Ok(create_filesystem(statements))
+}
+
+pub fn part_one(directory: &Directory) -> usize {
+    size_of_small_directories(directory, 100000).unwrap()
+}
+
+pub fn part_two(directory: &Directory) -> usize {
+    find_smallest_directory_to_delete(directory, 40000000).unwrap()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Directory<'a>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_ls_command() {
+        assert_eq!(Ok(Statement::List), parse_line("$ ls"));
+    }
+
+    #[test]
+    fn test_parse_cd_command() {
+        assert_eq!(Ok(Statement::ChangeDir("a")), parse_line("$ cd a"));
+        assert_eq!(Ok(Statement::ChangeDir("test")), parse_line("$ cd test"));
+        assert_eq!(Ok(Statement::ChangeDir("/")), parse_line("$ cd /"));
+        assert_eq!(Ok(Statement::ChangeDir("..")), parse_line("$ cd .."));
+    }
+
+    #[test]
+    fn test_directory_line() {
+        assert_eq!(Ok(Statement::Directory("a")), parse_line("dir a"));
+        assert_eq!(Ok(Statement::Directory("test")), parse_line("dir test"));
+    }
+
+    #[test]
+    fn test_file_line() {
+        assert_eq!(Ok(Statement::File("a", 100)), parse_line("100 a"));
+        assert_eq!(Ok(Statement::File("test", 39584)), parse_line("39584 test"));
+    }
+
+    #[test]
+    fn test_small_input() {
+        let data = fs::read_to_string("fixtures/example.txt").expect("Can't read input file");
+        let statements = parse_input(&data).unwrap();
+        let directory = create_filesystem(statements);
+        let result = size_of_small_directories(&directory, 100000);
+
+        assert_eq!(Ok(95437), result);
+    }
+
+    #[test]
+    fn test_small_input2() {
+        let data = fs::read_to_string("fixtures/example.txt").expect("Can't read input file");
+        let statements = parse_input(&data).unwrap();
+        let directory = create_filesystem(statements);
+        let result = find_smallest_directory_to_delete(&directory, 40000000).unwrap();
+
+        assert_eq!(24933642, result);
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day07>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,178 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use std::cmp::max;
+use std::collections::HashSet;
+
+pub fn part_one(tree_grid: &[Vec<u32>]) -> usize {
+    let width = tree_grid[0].len();
+    let height = tree_grid.len();
+
+    let edge_tree_count = height * 2 + width * 2 - 4;
+    let mut visible_trees = HashSet::new();
+
+    for (y, row) in tree_grid.iter().enumerate().take(height - 1).skip(1) {
+        // check visible from left
+        let mut highest_tree = row[0];
+        for (x, &tree) in row.iter().enumerate().take(width - 1).skip(1) {
+            if tree > highest_tree {
+                visible_trees.insert((x, y));
+                highest_tree = tree;
+            }
+        }
+
+        //check visible from right
+        highest_tree = row[width - 1];
+        for (x, &tree) in row.iter().enumerate().take(width - 1).skip(1).rev() {
+            if tree > highest_tree {
+                visible_trees.insert((x, y));
+                highest_tree = tree;
+            }
+        }
+    }
+
+    for x in 1..width - 1 {
+        // check visible from top
+        let mut highest_tree = tree_grid[0][x];
+        for (y, row) in tree_grid.iter().enumerate().take(height - 1).skip(1) {
+            if row[x] > highest_tree {
+                visible_trees.insert((x, y));
+                highest_tree = row[x];
+            }
+        }
+
+        //check visible from bottom
+        highest_tree = tree_grid[height - 1][x];
+        for (y, row) in tree_grid.iter().enumerate().take(height - 1).skip(1).rev() {
+            if row[x] > highest_tree {
+                visible_trees.insert((x, y));
+                highest_tree = row[x];
+            }
+        }
+    }
+
+    visible_trees.len() + edge_tree_count
+}
+
+pub fn part_two(tree_grid: &[Vec<u32>]) -> u32 {
+    let mut most_beautiful = 0;
+
+    for y in 0..tree_grid.len() {
+        for x in 0..tree_grid[y].len() {
+            most_beautiful = max(most_beautiful, calculate_vista(tree_grid, x, y));
+        }
+    }
+
+    most_beautiful
+}
+
+fn calculate_vista(tree_grid: &[Vec<u32>], x: usize, y: usize) -> u32 {
+    let width = tree_grid[0].len();
+    let height = tree_grid.len();
+
+    // look to the right
+    let mut visible_tree_count_right = 0;
+    for xt in x + 1..width {
+        visible_tree_count_right += 1;
+        if tree_grid[y][x] <= tree_grid[y][xt] {
+            break;
+        }
+    }
+
+    // look to the left
+    let mut visible_tree_count_left = 0;
+    for xt in (0..x).rev() {
+        visible_tree_count_left += 1;
+        if tree_grid[y][x] <= tree_grid[y][xt] {
+            break;
+        }
+    }
+
+    // look down
+    let mut visible_tree_count_down = 0;
+    for yt in y + 1..height {
+        visible_tree_count_down += 1;
+        if tree_grid[y][x] <= tree_grid[yt][x] {
+            break;
+        }
+    }
+
+    // look up
+    let mut visible_tree_count_up = 0;
+    for yt in (0..y).rev() {
+        visible_tree_count_up += 1;
+        if tree_grid[y][x] <= tree_grid[yt][x] {
+            break;
+        }
+    }
+
+    visible_tree_count_right
+        * visible_tree_count_left
+        * visible_tree_count_up
+        * visible_tree_count_down
+}
+
+pub fn build_tree_grid(data: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut tree_grid: Vec<Vec<u32>> = Vec::new();
+
+    for line in data.lines() {
+        let row = line.char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .ok_or_else(|| ParseError::new(&line[i..i + c.len_utf8()], "a digit"))
+            })
+            .collect::<Result<Vec<u32>, _>>()?;
+
+        if tree_grid.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(ParseError::new(line, format!("{} trees", tree_grid[0].len())));
+        }
+
+        tree_grid.push(row);
+    }
+
+    if tree_grid.is_empty() {
+        return Err(ParseError::after(data, "a row of trees"));
+    }
+
+    Ok(tree_grid)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        build_tree_grid(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_calculate_vista() {
+        let data = fs::read_to_string("fixtures/example.txt").expect("Can't read input file");
+        let tree_grid = build_tree_grid(&data).unwrap();
+
+        assert_eq!(4, calculate_vista(&tree_grid, 2, 1));
+        assert_eq!(8, calculate_vista(&tree_grid, 2, 3));
+    }
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day08>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,177 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+#[derive(Eq, Hash, PartialEq, Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub enum Action {
+    Right(i32),
+    Left(i32),
+    Down(i32),
+    Up(i32),
+}
+
+pub fn part_one(actions: &[Action]) -> usize {
+    let mut head = Point { x: 0, y: 0 };
+    let mut tail = Point { x: 0, y: 0 };
+    let mut visited_points = HashSet::new();
+
+    visited_points.insert(tail.clone());
+
+    for action in actions {
+        match action {
+            Action::Right(distance) => {
+                for _ in 0..*distance {
+                    head.x += 1;
+                    tail = move_tail(&head, &tail);
+                    visited_points.insert(tail.clone());
+                }
+            }
+            Action::Left(distance) => {
+                for _ in 0..*distance {
+                    head.x -= 1;
+                    tail = move_tail(&head, &tail);
+                    visited_points.insert(tail.clone());
+                }
+            }
+            Action::Down(distance) => {
+                for _ in 0..*distance {
+                    head.y += 1;
+                    tail = move_tail(&head, &tail);
+                    visited_points.insert(tail.clone());
+                }
+            }
+            Action::Up(distance) => {
+                for _ in 0..*distance {
+                    head.y -= 1;
+                    tail = move_tail(&head, &tail);
+                    visited_points.insert(tail.clone());
+                }
+            }
+        }
+    }
+
+    visited_points.len()
+}
+
+pub fn part_two(actions: &[Action]) -> usize {
+    let mut rope: Vec<Point> = (0..10).map(|_| Point { x: 0, y: 0}).collect();
+    let mut visited_points = HashSet::new();
+
+    for action in actions {
+        match action {
+            Action::Right(distance) => {
+                for _ in 0..*distance {
+                    rope[0].x += 1;
+                    for i in 0..9 {
+                        rope[i + 1] = move_tail(&rope[i], &rope[i + 1]);
+                    }
+                    visited_points.insert(rope[9].clone());
+                }
+            }
+            Action::Left(distance) => {
+                for _ in 0..*distance {
+                    rope[0].x -= 1;
+                    for i in 0..9 {
+                        rope[i + 1] = move_tail(&rope[i], &rope[i + 1]);
+                    }
+                    visited_points.insert(rope[9].clone());
+                }
+            }
+            Action::Down(distance) => {
+                for _ in 0..*distance {
+                    rope[0].y += 1;
+                    for i in 0..9 {
+                        rope[i + 1] = move_tail(&rope[i], &rope[i + 1]);
+                    }
+                    visited_points.insert(rope[9].clone());
+                }
+            }
+            Action::Up(distance) => {
+                for _ in 0..*distance {
+                    rope[0].y -= 1;
+                    for i in 0..9 {
+                        rope[i + 1] = move_tail(&rope[i], &rope[i + 1]);
+                    }
+                    visited_points.insert(rope[9].clone());
+                }
+            }
+        }
+    }
+
+    visited_points.len()
+}
+
+fn move_tail(head: &Point, tail: &Point) -> Point {
+    let mut new_tail = Point { x: tail.x, y: tail.y };
+    let x_distance = head.x - tail.x;
+    let y_distance = head.y - tail.y;
+
+    if x_distance.abs() > 1 {
+        new_tail.x += x_distance.signum();
+
+        if y_distance.abs() > 0 {
+            new_tail.y += y_distance.signum();
+        }
+    } else if y_distance.abs() > 1 {
+        new_tail.y += y_distance.signum();
+
+        if x_distance.abs() > 0 {
+            new_tail.x += x_distance.signum();
+        }
+    }
+
+    new_tail
+}
+
+pub fn parse(data: &str) -> Result<Vec<Action>, ParseError> {
+    data.lines()
+        .map(|line| {
+            let (direction_str, distance_str) = parse::split_once(line, " ")?;
+            let distance: i32 = parse::number(distance_str)?;
+
+            match direction_str {
+                "R" => Ok(Action::Right(distance)),
+                "L" => Ok(Action::Left(distance)),
+                "D" => Ok(Action::Down(distance)),
+                "U" => Ok(Action::Up(distance)),
+                _ => Err(ParseError::new(direction_str, "'R', 'L', 'D' or 'U'")),
+            }
+        })
+        .collect()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Action>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part_one(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part_two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day09>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,101 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use regex::Regex;
+
+fn convert_to_int(value: &str) -> i32 {
+    match value {
+        "1" | "one" => 1,
+        "2" | "two" => 2,
+        "3" | "three" => 3,
+        "4" | "four" => 4,
+        "5" | "five" => 5,
+        "6" | "six" => 6,
+        "7" | "seven" => 7,
+        "8" | "eight" => 8,
+        "9" | "nine" => 9,
+        _ => panic!("Not a digit"),
+    }
+}
+
+const DIGIT: &str = "[1-9]";
+const DIGIT_OR_WORD: &str = "[1-9]|one|two|three|four|five|six|seven|eight|nine";
+
+/// The sum of the calibration values, or `None` if a line has no digit.
+fn sum_values(lines: &[&str], digit: &str) -> Option<i32> {
+    let first_re = Regex::new(&format!(".*?({}).*", digit)).unwrap();
+    let last_re = Regex::new(&format!(".*({}).*?", digit)).unwrap();
+    let mut sum = 0;
+
+    for line in lines {
+        let (_, [first]) = first_re.captures(line)?.extract();
+        let (_, [last]) = last_re.captures(line)?.extract();
+
+        sum += convert_to_int(first) * 10 + convert_to_int(last);
+    }
+
+    Some(sum)
+}
+
+/// Every line needs a digit, at least a spelled-out one: the second example
+/// has lines only part two can read.
+pub fn parse(data: &str) -> Result<Vec<&str>, ParseError> {
+    let digit_re = Regex::new(DIGIT_OR_WORD).unwrap();
+
+    data.lines()
+        .map(|line| {
+            parse::check_chars(line, |c| c.is_ascii_alphanumeric(), "a letter or a digit")?;
+
+            if !digit_re.is_match(line) {
+                return Err(ParseError::new(line, "a digit"));
+            }
+
+            Ok(line)
+        })
+        .collect()
+}
+
+/// `None` if a line only has spelled-out digits.
+pub fn part1(lines: &[&str]) -> Option<i32> {
+    sum_values(lines, DIGIT)
+}
+
+pub fn part2(lines: &[&str]) -> i32 {
+    sum_values(lines, DIGIT_OR_WORD).expect("Parse checks every line for a digit")
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day01>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_lines_without_digits() {
+        assert!(parse("a1b\nabc\n").is_err());
+        assert_eq!(None, part1(&parse("1abc\ntwo\n").unwrap()));
+    }
+}
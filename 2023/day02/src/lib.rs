@@ -0,0 +1,130 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::cmp::max;
+
+struct Round {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+pub struct Game {
+    id: u32,
+    rounds: Vec<Round>,
+}
+
+fn parse_game(line: &str) -> Result<Game, ParseError> {
+    let (game_str, rounds_str) = parse::split_once(line, ": ")?;
+
+    let game_id = parse::number(parse::strip_prefix(game_str, "Game ")?)?;
+    let mut rounds = Vec::new();
+
+    for round_str in rounds_str.split("; ") {
+        let mut round = Round {
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+
+        for cube_str in round_str.split(", ") {
+            let (count, color) = parse::split_once(cube_str, " ")?;
+            match color {
+                "red" => round.red = parse::number(count)?,
+                "green" => round.green = parse::number(count)?,
+                "blue" => round.blue = parse::number(count)?,
+                _ => return Err(ParseError::new(color, "'red', 'green' or 'blue'")),
+            }
+        }
+
+        rounds.push(round);
+    }
+
+    Ok(Game {
+        id: game_id,
+        rounds,
+    })
+}
+
+fn check_game(game: &Game, max_round: &Round) -> bool {
+    for round in &game.rounds {
+        if round.red > max_round.red || round.green > max_round.green || round.blue > max_round.blue
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+pub fn part1(games: &[Game]) -> u32 {
+    let max_round = Round {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+
+    games
+        .iter()
+        .filter(|game| check_game(game, &max_round))
+        .map(|game| game.id)
+        .sum()
+}
+
+fn find_max_round(game: &Game) -> Round {
+    let mut max_round = Round {
+        red: 0,
+        green: 0,
+        blue: 0,
+    };
+
+    for round in &game.rounds {
+        max_round.red = max(max_round.red, round.red);
+        max_round.green = max(max_round.green, round.green);
+        max_round.blue = max(max_round.blue, round.blue);
+    }
+
+    max_round
+}
+
+pub fn part2(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(find_max_round)
+        .map(|round| round.red * round.green * round.blue)
+        .sum()
+}
+
+pub fn parse(data: &str) -> Result<Vec<Game>, ParseError> {
+    data.lines().map(parse_game).collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
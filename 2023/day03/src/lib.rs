@@ -0,0 +1,267 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Clone, Copy)]
+struct NumberLocation {
+    line_number: usize,
+    start: usize,
+    end: usize,
+}
+
+fn find_number_locations(data: &str) -> Vec<NumberLocation> {
+    let mut locations: Vec<NumberLocation> = Vec::new();
+
+    for (line_number, line) in data.lines().enumerate() {
+        let mut location: (Option<usize>, Option<usize>) = (None, None);
+
+        for (col_number, ch) in line.chars().enumerate() {
+            if ch.is_numeric() {
+                if location.0.is_some() {
+                    location.1 = Some(col_number);
+                } else {
+                    location.0 = Some(col_number);
+                }
+            } else if location.0.is_some() && location.1.is_none() {
+                locations.push(NumberLocation {
+                    line_number,
+                    start: location.0.unwrap(),
+                    end: location.0.unwrap(),
+                });
+                location = (None, None);
+            } else if location.0.is_some() && location.1.is_some() {
+                locations.push(NumberLocation {
+                    line_number,
+                    start: location.0.unwrap(),
+                    end: location.1.unwrap(),
+                });
+                location = (None, None);
+            }
+        }
+
+        if let Some(start) = location.0 {
+            locations.push(NumberLocation {
+                line_number,
+                start,
+                end: location.1.unwrap_or(start),
+            });
+        }
+    }
+
+    locations
+}
+
+fn is_symbol(ch: char) -> bool {
+    !ch.is_numeric() && ch != '.'
+}
+
+fn find_gears(map: &[Vec<char>], col_count: usize) -> Vec<(usize, usize)> {
+    let mut locations: Vec<(usize, usize)> = Vec::new();
+
+    for (y, row) in map.iter().enumerate() {
+        for (x, &ch) in row.iter().enumerate().take(col_count) {
+            if ch == '*' {
+                locations.push((x, y));
+            }
+        }
+    }
+
+    locations
+}
+
+fn bound_inc(a: usize, bound: usize) -> usize {
+    if a < bound {
+        a + 1
+    } else {
+        a
+    }
+}
+
+fn bound_dec(a: usize, bound: usize) -> usize {
+    if bound < a {
+        a - 1
+    } else {
+        a
+    }
+}
+
+fn get_number(map: &[Vec<char>], location: &NumberLocation) -> usize {
+    let number_string =
+        String::from_iter(map[location.line_number][location.start..=location.end].iter());
+
+    number_string.parse::<usize>().unwrap()
+}
+
+pub struct Schematic {
+    map: Vec<Vec<char>>,
+    number_locations: Vec<NumberLocation>,
+}
+
+pub fn parse(data: &str) -> Result<Schematic, ParseError> {
+    let width = data.lines().next().map_or(0, str::len);
+
+    for line in data.lines() {
+        parse::check_chars(line, |c| c.is_ascii_graphic(), "a digit, '.' or a symbol")?;
+        if line.len() != width {
+            return Err(ParseError::new(line, format!("{} characters", width)));
+        }
+    }
+
+    let map: Vec<Vec<char>> = data.lines().map(|line| line.chars().collect()).collect();
+
+    if map.is_empty() {
+        return Err(ParseError::after(data, "an engine schematic"));
+    }
+    let number_locations = find_number_locations(data);
+
+    Ok(Schematic {
+        map,
+        number_locations,
+    })
+}
+
+pub fn part1(schematic: &Schematic) -> usize {
+    let map = &schematic.map;
+    let number_locations = &schematic.number_locations;
+    let row_count = map.len();
+    let col_count = map[0].len();
+
+    let mut valid_number_locations: Vec<NumberLocation> = Vec::new();
+
+    for location in number_locations.iter() {
+        if location.line_number > 0 {
+            // check top row
+            for &ch in &map[location.line_number - 1][location.start..=location.end] {
+                if is_symbol(ch) {
+                    valid_number_locations.push(*location);
+                }
+            }
+        }
+
+        if location.line_number < row_count - 1 {
+            // check bottom row
+            for &ch in &map[location.line_number + 1][location.start..=location.end] {
+                if is_symbol(ch) {
+                    valid_number_locations.push(*location);
+                }
+            }
+        }
+
+        if location.start > 0 {
+            // check left symbol
+            if is_symbol(map[location.line_number][location.start - 1]) {
+                valid_number_locations.push(*location);
+            }
+        }
+
+        if location.end < col_count - 1 {
+            // check right symbol
+            if is_symbol(map[location.line_number][location.end + 1]) {
+                valid_number_locations.push(*location);
+            }
+        }
+
+        if location.line_number > 0 && location.start > 0 {
+            // check top left symbol
+            if is_symbol(map[location.line_number - 1][location.start - 1]) {
+                valid_number_locations.push(*location);
+            }
+        }
+
+        if location.line_number > 0 && location.end < col_count - 1 {
+            // check top right symbol
+            if is_symbol(map[location.line_number - 1][location.end + 1]) {
+                valid_number_locations.push(*location);
+            }
+        }
+
+        if location.line_number < row_count - 1 && location.start > 0 {
+            // check bottom left
+            if is_symbol(map[location.line_number + 1][location.start - 1]) {
+                valid_number_locations.push(*location);
+            }
+        }
+
+        if location.line_number < row_count - 1 && location.end < col_count - 1 {
+            // check bottom right
+            if is_symbol(map[location.line_number + 1][location.end + 1]) {
+                valid_number_locations.push(*location);
+            }
+        }
+    }
+
+    let mut sum: usize = 0;
+
+    for location in valid_number_locations {
+        sum += get_number(map, &location);
+    }
+
+    sum
+}
+
+pub fn part2(schematic: &Schematic) -> usize {
+    let map = &schematic.map;
+    let number_locations = &schematic.number_locations;
+    let row_count = map.len();
+    let col_count = map[0].len();
+
+    let gear_locations = find_gears(map, col_count);
+    let mut gear_numbers: Vec<Vec<NumberLocation>> = Vec::new();
+
+    for location in gear_locations {
+        let numbers: Vec<NumberLocation> = number_locations
+            .iter()
+            .filter(|num_loc| {
+                (bound_dec(num_loc.line_number, 0)..=bound_inc(num_loc.line_number, row_count))
+                    .contains(&location.1)
+                    && (bound_dec(num_loc.start, 0)..=bound_inc(num_loc.end, row_count))
+                        .contains(&location.0)
+            })
+            .copied()
+            .collect();
+
+        if numbers.len() == 2 {
+            gear_numbers.push(numbers);
+        }
+    }
+
+    gear_numbers
+        .iter()
+        .map(|numbers| {
+            numbers
+                .iter()
+                .map(|number| get_number(map, number))
+                .product::<usize>()
+        })
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Schematic;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day03>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
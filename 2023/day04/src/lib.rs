@@ -0,0 +1,92 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+#[derive(Clone)]
+pub struct Card {
+    winning_numbers: HashSet<usize>,
+    numbers: HashSet<usize>,
+    copies: usize,
+}
+
+fn parse_number_list(number_str: &str) -> Result<HashSet<usize>, ParseError> {
+    number_str.split_whitespace().map(parse::number).collect()
+}
+
+fn parse_card(line: &str) -> Result<Card, ParseError> {
+    let (_, all_numbers_str) = parse::split_once(line, ": ")?;
+    let (winning_numbers_str, numbers_str) = parse::split_once(all_numbers_str, " | ")?;
+
+    Ok(Card {
+        winning_numbers: parse_number_list(winning_numbers_str)?,
+        numbers: parse_number_list(numbers_str)?,
+        copies: 1,
+    })
+}
+
+fn count_matching_numbers(card: &Card) -> u32 {
+    card.numbers.intersection(&card.winning_numbers).count() as u32
+}
+
+fn calculate_score(card: &Card) -> usize {
+    let matching_numbers_count = count_matching_numbers(card);
+
+    if matching_numbers_count == 0 {
+        0
+    } else {
+        2usize.pow(matching_numbers_count - 1)
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Card>, ParseError> {
+    data.lines().map(parse_card).collect()
+}
+
+pub fn part1(cards: &[Card]) -> usize {
+    cards.iter().map(calculate_score).sum()
+}
+
+pub fn part2(cards: &[Card]) -> usize {
+    let mut cards = cards.to_vec();
+
+    for index in 0..cards.len() {
+        let matching_numbers_count = count_matching_numbers(&cards[index]);
+
+        for index2 in index + 1..cards.len().min(index + 1 + matching_numbers_count as usize) {
+            cards[index2].copies += cards[index].copies;
+        }
+    }
+
+    cards.iter().map(|card| card.copies).sum()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+fn parse_number_list(number_str: &str) -> Result<Vec<usize>, ParseError> {
+    number_str.split_whitespace().map(parse::number).collect()
+}
+
+fn join_digits(numbers: &[usize]) -> usize {
+    numbers.iter().fold(0, |acc, n| {
+        acc * 10usize.pow(n.to_string().len() as u32) + n
+    })
+}
+
+fn run_races(races: &[(usize, usize)]) -> usize {
+    races
+        .iter()
+        .map(|(time, record)| {
+            (0..=*time)
+                .map(|t| t * (time - t))
+                .filter(|d| *d > *record)
+                .count()
+        })
+        .product()
+}
+
+pub fn parse(data: &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    let mut lines = data.lines();
+    let times_line = lines
+        .next()
+        .ok_or_else(|| ParseError::after(data, "a list of times"))?;
+    let records_line = lines
+        .next()
+        .ok_or_else(|| ParseError::after(times_line, "a list of distances"))?;
+
+    let times = parse_number_list(parse::strip_prefix(times_line, "Time:")?)?;
+    let records = parse_number_list(parse::strip_prefix(records_line, "Distance:")?)?;
+
+    if times.len() != records.len() {
+        return Err(ParseError::new(records_line, "a distance for every time"));
+    }
+
+    Ok((times, records))
+}
+
+pub fn part1((times, records): &(Vec<usize>, Vec<usize>)) -> usize {
+    let races: Vec<(usize, usize)> = times.iter().copied().zip(records.iter().copied()).collect();
+
+    run_races(&races)
+}
+
+pub fn part2((times, records): &(Vec<usize>, Vec<usize>)) -> usize {
+    let long_race = (join_digits(times), join_digits(records));
+
+    run_races(&[long_race])
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input<'a> = (Vec<usize>, Vec<usize>);
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day06>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
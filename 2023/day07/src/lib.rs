@@ -0,0 +1,263 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialOrd, PartialEq, Ord, Eq)]
+enum Strength {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+#[derive(Debug, PartialOrd, PartialEq, Ord, Eq, Hash, Clone, Copy)]
+enum Card {
+    A,
+    K,
+    Q,
+    J,
+    T,
+    C9,
+    C8,
+    C7,
+    C6,
+    C5,
+    C4,
+    C3,
+    C2,
+    Joker,
+}
+
+impl Card {
+    fn from_char(c: char, with_joker: bool) -> Self {
+        match c {
+            'A' => Self::A,
+            'K' => Self::K,
+            'Q' => Self::Q,
+            'J' => {
+                if with_joker {
+                    Self::Joker
+                } else {
+                    Self::J
+                }
+            }
+            'T' => Self::T,
+            '9' => Self::C9,
+            '8' => Self::C8,
+            '7' => Self::C7,
+            '6' => Self::C6,
+            '5' => Self::C5,
+            '4' => Self::C4,
+            '3' => Self::C3,
+            '2' => Self::C2,
+            _ => unreachable!("Unknown card {}", c),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Hand {
+    cards: Vec<Card>,
+    bid: usize,
+    strength: Strength,
+}
+
+impl Hand {
+    fn new(cards: &str, bid: usize, with_joker: bool) -> Self {
+        let cards: Vec<Card> = cards
+            .chars()
+            .map(|c| Card::from_char(c, with_joker))
+            .collect();
+
+        let card_counts = count_cards(&cards, with_joker);
+        let strength = calculate_strength(&card_counts);
+
+        Hand {
+            cards,
+            bid,
+            strength,
+        }
+    }
+}
+
+impl Eq for Hand {}
+
+impl PartialEq<Self> for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.strength == other.strength && self.cards.eq(&other.cards)
+    }
+}
+
+impl PartialOrd<Self> for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.strength.cmp(&other.strength) {
+            Ordering::Equal => {
+                for (a, b) in self.cards.iter().zip(&other.cards) {
+                    match a.cmp(b) {
+                        Ordering::Equal => {}
+                        o => return o,
+                    }
+                }
+
+                Ordering::Equal
+            }
+            o => o,
+        }
+    }
+}
+
+fn count_cards(cards: &[Card], with_jokers: bool) -> Vec<usize> {
+    let mut card_counts: HashMap<Card, usize> = HashMap::new();
+
+    for card in cards {
+        if let Some(count) = card_counts.get(card) {
+            card_counts.insert(*card, count + 1);
+        } else {
+            card_counts.insert(*card, 1);
+        }
+    }
+
+    if with_jokers {
+        if let Some(joker_count) = card_counts.remove(&Card::Joker) {
+            if let Some((high_card, count)) =
+                card_counts
+                    .iter()
+                    .reduce(|(card_a, count_a), (card_b, count_b)| {
+                        if count_a > count_b {
+                            (card_a, count_a)
+                        } else {
+                            (card_b, count_b)
+                        }
+                    })
+            {
+                card_counts.insert(*high_card, count + joker_count);
+            } else {
+                card_counts.insert(Card::Joker, joker_count);
+            }
+        }
+    }
+
+    card_counts.into_values().collect()
+}
+
+fn calculate_strength(card_counts: &[usize]) -> Strength {
+    if is_five_of_a_kind(card_counts) {
+        Strength::FiveOfAKind
+    } else if is_four_of_a_kind(card_counts) {
+        Strength::FourOfAKind
+    } else if is_full_house(card_counts) {
+        Strength::FullHouse
+    } else if is_three_of_a_kind(card_counts) {
+        Strength::ThreeOfAKind
+    } else if is_two_pair(card_counts) {
+        Strength::TwoPair
+    } else if is_one_pair(card_counts) {
+        Strength::OnePair
+    } else {
+        Strength::HighCard
+    }
+}
+
+fn is_five_of_a_kind(card_counts: &[usize]) -> bool {
+    card_counts.contains(&5)
+}
+
+fn is_four_of_a_kind(card_counts: &[usize]) -> bool {
+    card_counts.contains(&4)
+}
+
+fn is_full_house(card_counts: &[usize]) -> bool {
+    card_counts.contains(&3) && card_counts.contains(&2)
+}
+
+fn is_three_of_a_kind(card_counts: &[usize]) -> bool {
+    card_counts.contains(&3) && !card_counts.contains(&2)
+}
+
+fn is_two_pair(card_counts: &[usize]) -> bool {
+    card_counts.iter().filter(|c| **c == 2).count() == 2
+}
+
+fn is_one_pair(card_counts: &[usize]) -> bool {
+    card_counts.iter().filter(|c| **c == 2).count() == 1
+}
+
+fn calculate_result(hands: &[(&str, usize)], with_joker: bool) -> usize {
+    let mut hands: Vec<Hand> = hands
+        .iter()
+        .map(|&(cards, bid)| Hand::new(cards, bid, with_joker))
+        .collect();
+
+    hands.sort();
+
+    hands
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(rank, hand)| (rank + 1) * hand.bid)
+        .sum()
+}
+
+fn parse_hand(line: &str) -> Result<(&str, usize), ParseError> {
+    let (cards, bid) = parse::split_once(line, " ")?;
+    let cards = parse::check_chars(cards, |c| "AKQJT98765432".contains(c), "a card")?;
+
+    if cards.chars().count() != 5 {
+        return Err(ParseError::new(cards, "five cards"));
+    }
+
+    Ok((cards, parse::number(bid)?))
+}
+
+pub fn parse(data: &str) -> Result<Vec<(&str, usize)>, ParseError> {
+    data.lines().map(parse_hand).collect()
+}
+
+pub fn part1(hands: &[(&str, usize)]) -> usize {
+    calculate_result(hands, false)
+}
+
+pub fn part2(hands: &[(&str, usize)]) -> usize {
+    calculate_result(hands, true)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<(&'a str, usize)>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day07>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
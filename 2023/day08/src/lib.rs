@@ -0,0 +1,212 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use aoc_math::{crt, next_congruent};
+use regex::Regex;
+use std::collections::HashMap;
+
+/// The steps at which a ghost stands on a node ending in 'Z'. Before
+/// `cycle_start` those are listed in `hits`; from then on the walk repeats
+/// every `period` steps and hits at every step congruent to one of
+/// `residues`.
+struct Ghost {
+    hits: Vec<i64>,
+    cycle_start: i64,
+    period: i64,
+    residues: Vec<i64>,
+}
+
+impl Ghost {
+    fn walk(start_location: &str, directions: &[char], map: &Map) -> Self {
+        let mut seen: HashMap<(&str, usize), i64> = HashMap::new();
+        let mut hits = Vec::new();
+        let mut current_location = start_location;
+
+        for step in 0.. {
+            let direction_index = step as usize % directions.len();
+
+            if let Some(&cycle_start) = seen.get(&(current_location, direction_index)) {
+                let period = step - cycle_start;
+                let residues = hits
+                    .iter()
+                    .filter(|&&hit| hit >= cycle_start)
+                    .map(|hit| hit % period)
+                    .collect();
+                hits.retain(|&hit| hit < cycle_start);
+
+                return Ghost {
+                    hits,
+                    cycle_start,
+                    period,
+                    residues,
+                };
+            }
+
+            seen.insert((current_location, direction_index), step);
+            if current_location.ends_with('Z') {
+                hits.push(step);
+            }
+
+            let (l_dest, r_dest) = map.get(current_location).unwrap();
+            current_location = match directions[direction_index] {
+                'L' => l_dest,
+                'R' => r_dest,
+                d => unreachable!("Unknown direction '{}'", d),
+            };
+        }
+
+        unreachable!()
+    }
+
+    fn is_at_end(&self, step: i64) -> bool {
+        if step < self.cycle_start {
+            self.hits.contains(&step)
+        } else {
+            self.residues.contains(&(step % self.period))
+        }
+    }
+}
+
+/// The first step at which every ghost stands on a node ending in 'Z'.
+fn find_common_end(ghosts: &[Ghost]) -> Option<i64> {
+    let cycle_start = ghosts.iter().map(|g| g.cycle_start).max()?;
+
+    // Before every ghost is inside its cycle, try each step.
+    if let Some(step) = (0..cycle_start).find(|&step| ghosts.iter().all(|g| g.is_at_end(step))) {
+        return Some(step);
+    }
+
+    // Afterwards, pick one residue per ghost and solve the congruences.
+    let mut combinations: Vec<Vec<(i64, i64)>> = vec![Vec::new()];
+    for ghost in ghosts {
+        combinations = combinations
+            .into_iter()
+            .flat_map(|combination| {
+                ghost.residues.iter().map(move |&residue| {
+                    let mut combination = combination.clone();
+                    combination.push((residue, ghost.period));
+                    combination
+                })
+            })
+            .collect();
+    }
+
+    combinations
+        .into_iter()
+        .filter_map(crt)
+        .map(|(step, period)| next_congruent(step, period, cycle_start))
+        .min()
+}
+
+fn find_end_location(
+    start_location: &str,
+    directions: &[char],
+    map: &HashMap<&str, (&str, &str)>,
+) -> usize {
+    let mut current_location = start_location;
+    let mut step_count = 0;
+
+    while !current_location.ends_with('Z') {
+        current_location = run_directions(directions, map, current_location);
+        step_count += directions.len();
+    }
+
+    step_count
+}
+
+type Map<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+pub fn part1((directions, map): &(Vec<char>, Map)) -> usize {
+    find_end_location("AAA", directions, map)
+}
+
+pub fn part2((directions, map): &(Vec<char>, Map)) -> usize {
+    let ghosts: Vec<Ghost> = map
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(|start| Ghost::walk(start, directions, map))
+        .collect();
+
+    find_common_end(&ghosts).expect("The ghosts never all reach an end together") as usize
+}
+
+pub fn parse(data: &str) -> Result<(Vec<char>, Map<'_>), ParseError> {
+    let mut lines = data.lines();
+    let directions_line = lines
+        .next()
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::after(data, "a list of directions"))?;
+    let directions: Vec<char> =
+        parse::check_chars(directions_line, |c| c == 'L' || c == 'R', "'L' or 'R'")?
+            .chars()
+            .collect();
+    lines.next();
+
+    let mut map: Map = HashMap::new();
+    let r = Regex::new(r"^([0-9A-Z]+) = \(([0-9A-Z]+), ([0-9A-Z]+)\)$").unwrap();
+
+    for line in lines {
+        let (_, [start, l_dest, r_dest]) = r
+            .captures(line)
+            .ok_or_else(|| ParseError::new(line, "a node like 'AAA = (BBB, CCC)'"))?
+            .extract();
+        map.insert(start, (l_dest, r_dest));
+    }
+
+    let mut destinations = map.values().flat_map(|&(l_dest, r_dest)| [l_dest, r_dest]);
+
+    if let Some(dest) = destinations.find(|dest| !map.contains_key(dest)) {
+        return Err(ParseError::new(dest, "a node that is in the map"));
+    }
+
+    Ok((directions, map))
+}
+
+fn run_directions<'a>(
+    directions: &[char],
+    map: &HashMap<&'a str, (&'a str, &'a str)>,
+    start_location: &'a str,
+) -> &'a str {
+    let mut current_location = start_location;
+
+    for d in directions {
+        let (l_dest, r_dest) = map.get(current_location).unwrap();
+        current_location = match d {
+            'L' => l_dest,
+            'R' => r_dest,
+            _ => unreachable!("Unknown direction '{}'", d),
+        };
+    }
+
+    current_location
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input<'a> = (Vec<char>, Map<'a>);
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day08>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+fn parse_number_list(number_str: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers: Vec<i64> = number_str
+        .split_whitespace()
+        .map(parse::number)
+        .collect::<Result<_, _>>()?;
+
+    if numbers.is_empty() {
+        return Err(ParseError::new(number_str, "a list of numbers"));
+    }
+
+    Ok(numbers)
+}
+
+fn diff_list(numbers: &[i64]) -> Vec<i64> {
+    numbers.windows(2).map(|n| n[1] - n[0]).collect()
+}
+
+fn is_all_zero(numbers: &[i64]) -> bool {
+    numbers.iter().all(|n| *n == 0)
+}
+
+fn build_diff_stack(numbers: Vec<i64>) -> Vec<Vec<i64>> {
+    let mut numbers_stack: Vec<Vec<i64>> = Vec::new();
+    let mut index = 0;
+    numbers_stack.push(numbers);
+
+    while !is_all_zero(&numbers_stack[index]) {
+        let diff_list = diff_list(&numbers_stack[index]);
+        numbers_stack.push(diff_list);
+        index += 1;
+    }
+
+    numbers_stack
+}
+
+fn find_next_number(numbers: Vec<i64>) -> i64 {
+    build_diff_stack(numbers)
+        .iter()
+        .rev()
+        .fold(0, |acc, n| n.last().unwrap() + acc)
+}
+
+fn find_prev_number(numbers: Vec<i64>) -> i64 {
+    build_diff_stack(numbers)
+        .iter()
+        .rev()
+        .fold(0, |acc, n| n.first().unwrap() - acc)
+}
+
+pub fn parse(data: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    data.lines().map(parse_number_list).collect()
+}
+
+pub fn part1(lists: &[Vec<i64>]) -> i64 {
+    lists.iter().cloned().map(find_next_number).sum()
+}
+
+pub fn part2(lists: &[Vec<i64>]) -> i64 {
+    lists.iter().cloned().map(find_prev_number).sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Vec<i64>>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day09>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
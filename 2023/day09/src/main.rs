@@ -1,64 +1,10 @@
-use std::fs;
-
-fn parse_number_list(number_str: &str) -> Vec<i64> {
-    number_str
-        .split_whitespace()
-        .map(|n| n.parse::<i64>().unwrap())
-        .collect()
-}
-
-fn diff_list(numbers: &[i64]) -> Vec<i64> {
-    numbers.windows(2).map(|n| n[1] - n[0]).collect()
-}
-
-fn is_all_zero(numbers: &[i64]) -> bool {
-    numbers.iter().all(|n| *n == 0)
-}
-
-fn build_diff_stack(numbers: Vec<i64>) -> Vec<Vec<i64>> {
-    let mut numbers_stack: Vec<Vec<i64>> = Vec::new();
-    let mut index = 0;
-    numbers_stack.push(numbers);
-
-    while !is_all_zero(&numbers_stack[index]) {
-        let diff_list = diff_list(&numbers_stack[index]);
-        numbers_stack.push(diff_list);
-        index += 1;
-    }
-
-    numbers_stack
-}
-
-fn find_next_number(numbers: Vec<i64>) -> i64 {
-    build_diff_stack(numbers)
-        .iter()
-        .rev()
-        .fold(0, |acc, n| n.last().unwrap() + acc)
-}
-
-fn find_prev_number(numbers: Vec<i64>) -> i64 {
-    build_diff_stack(numbers)
-        .iter()
-        .rev()
-        .fold(0, |acc, n| n.first().unwrap() - acc)
-}
+use aoc_common::input;
+use day09::{parse, part1, part2};
 
 fn main() {
-    let data = fs::read_to_string("day9.txt").expect("Can't read input file");
-
-    let part1_result: i64 = data
-        .lines()
-        .map(parse_number_list)
-        .map(find_next_number)
-        .sum();
-
-    println!("Day 9 Part 1: {}", part1_result);
-
-    let part1_result: i64 = data
-        .lines()
-        .map(parse_number_list)
-        .map(find_prev_number)
-        .sum();
+    let data = input::load_or_exit(2023, 9);
+    let lists = input::parse_or_exit(&data, parse);
 
-    println!("Day 9 Part 2: {}", part1_result);
+    println!("Day 9 Part 1: {}", part1(&lists));
+    println!("Day 9 Part 2: {}", part2(&lists));
 }
@@ -0,0 +1,169 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Params, Solution};
+
+#[derive(Clone)]
+pub struct Map {
+    galaxies: Vec<(i64, i64)>,
+    empty_cols: Vec<i64>,
+    empty_rows: Vec<i64>,
+}
+
+impl Map {
+    fn expand_space(&self, factor: i64) -> Map {
+        let new_galaxies = self
+            .galaxies
+            .iter()
+            .map(|g| {
+                let col_count = self.empty_cols.iter().filter(|x| *x < &g.0).count() as i64;
+                let row_count = self.empty_rows.iter().filter(|y| *y < &g.1).count() as i64;
+
+                (
+                    if col_count != 0 {
+                        g.0 + col_count * (factor - 1)
+                    } else {
+                        g.0
+                    },
+                    if row_count != 0 {
+                        g.1 + row_count * (factor - 1)
+                    } else {
+                        g.1
+                    },
+                )
+            })
+            .collect();
+
+        Map {
+            galaxies: new_galaxies,
+            empty_rows: self.empty_rows.clone(),
+            empty_cols: self.empty_cols.clone(),
+        }
+    }
+}
+
+fn calculate_paths(mut map: Map) -> i64 {
+    let mut paths_lengths: Vec<i64> = Vec::new();
+
+    while let Some(galaxy) = &map.galaxies.pop() {
+        for g in &map.galaxies {
+            paths_lengths.push(shortest_path(galaxy, g));
+        }
+    }
+
+    paths_lengths.iter().sum()
+}
+
+pub fn parse(data: &str) -> Result<Map, ParseError> {
+    let mut map: Vec<Vec<char>> = Vec::new();
+
+    for line in data.lines() {
+        parse::check_chars(line, |c| c == '.' || c == '#', "'.' or '#'")?;
+
+        if let Some(first) = map.first().filter(|first| first.len() != line.len()) {
+            return Err(ParseError::new(line, format!("{} columns", first.len())));
+        }
+
+        map.push(line.chars().collect());
+    }
+
+    if map.is_empty() {
+        return Err(ParseError::after(data, "an image of the galaxies"));
+    }
+
+    let galaxies = parse_galaxies(&map);
+    let (empty_cols, empty_rows) = find_empty_space(&map);
+
+    Ok(Map {
+        galaxies,
+        empty_cols,
+        empty_rows,
+    })
+}
+
+fn shortest_path(g1: &(i64, i64), g2: &(i64, i64)) -> i64 {
+    (g2.0 - g1.0).abs() + (g2.1 - g1.1).abs()
+}
+
+fn find_empty_space(map: &[Vec<char>]) -> (Vec<i64>, Vec<i64>) {
+    let width = map[0].len();
+    let mut empty_cols: Vec<i64> = Vec::new();
+
+    for x in 0..width {
+        if map.iter().map(|row| row[x]).all(|c| c == '.') {
+            empty_cols.push(x as i64);
+        }
+    }
+
+    let mut empty_rows: Vec<i64> = Vec::new();
+
+    for (y, row) in map.iter().enumerate() {
+        if row.iter().all(|&c| c == '.') {
+            empty_rows.push(y as i64);
+        }
+    }
+
+    (empty_cols, empty_rows)
+}
+
+fn parse_galaxies(map: &[Vec<char>]) -> Vec<(i64, i64)> {
+    let mut galaxies: Vec<(i64, i64)> = Vec::new();
+
+    for (y, row) in map.iter().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            if c == '#' {
+                galaxies.push((x as i64, y as i64));
+            }
+        }
+    }
+    galaxies
+}
+
+pub fn part1(map: &Map) -> i64 {
+    calculate_paths(map.expand_space(2))
+}
+
+pub fn part2(map: &Map, factor: i64) -> i64 {
+    calculate_paths(map.expand_space(factor))
+}
+
+pub struct Day11;
+
+pub struct Observation {
+    map: Map,
+    factor: i64,
+}
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Observation;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(data, &Params::default())
+    }
+
+    fn parse_with<'a>(data: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok(Observation {
+            map: parse(data)?,
+            factor: params.get("factor", 1_000_000),
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input.map).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input.map, input.factor).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day11>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,226 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum Spring {
+    Working,
+    Defective,
+    Unknown,
+}
+
+impl Spring {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Self::Working),
+            '.' => Some(Self::Defective),
+            '?' => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Entry {
+    pattern: Vec<Spring>,
+    numbers: Vec<usize>,
+}
+
+impl Entry {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let (pattern, numbers_str) = parse::split_once(line, " ")?;
+
+        let numbers: Vec<usize> = numbers_str
+            .split(',')
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        let pattern = parse::check_chars(
+            pattern,
+            |c| Spring::from_char(c).is_some(),
+            "'#', '.' or '?'",
+        )?;
+
+        Ok(Entry {
+            pattern: pattern.chars().filter_map(Spring::from_char).collect(),
+            numbers,
+        })
+    }
+
+    fn head(&self) -> Spring {
+        self.pattern[0]
+    }
+
+    fn tail(&self) -> Vec<Spring> {
+        let mut new_pattern = self.pattern.clone();
+        new_pattern.remove(0);
+
+        new_pattern
+    }
+
+    fn expand(&self) -> Self {
+        let mut new_pattern: Vec<Spring> = Vec::new();
+        for _ in 0..5 {
+            new_pattern.append(&mut self.pattern.clone());
+            new_pattern.push(Spring::Unknown);
+        }
+        new_pattern.pop();
+
+        Self {
+            pattern: new_pattern,
+            numbers: self.numbers.repeat(5),
+        }
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Entry>, ParseError> {
+    data.lines().map(Entry::parse).collect()
+}
+
+fn count_arrangements(entry: Entry, memo: &mut HashMap<Entry, usize>) -> usize {
+    if let Some(&count) = memo.get(&entry) {
+        return count;
+    }
+
+    if entry.pattern.is_empty() {
+        return if entry.numbers.is_empty() { 1 } else { 0 };
+    }
+
+    let current_spring = entry.head();
+
+    match current_spring {
+        Spring::Working => {
+            if entry.numbers.is_empty() {
+                memo.insert(entry, 0);
+                0
+            } else {
+                let spring_count = entry.numbers[0];
+                if entry.pattern.len() >= spring_count
+                    && entry
+                        .pattern
+                        .iter()
+                        .take(spring_count)
+                        .all(|&s| s != Spring::Defective)
+                {
+                    let mut new_pattern: Vec<Spring> = entry
+                        .pattern
+                        .clone()
+                        .into_iter()
+                        .skip(spring_count)
+                        .collect();
+                    if new_pattern.is_empty() || new_pattern[0] != Spring::Working {
+                        if !new_pattern.is_empty() {
+                            new_pattern[0] = Spring::Defective;
+                        }
+
+                        count_arrangements(
+                            Entry {
+                                pattern: new_pattern,
+                                numbers: entry.numbers.into_iter().skip(1).collect(),
+                            },
+                            memo,
+                        )
+                    } else {
+                        memo.insert(entry, 0);
+                        0
+                    }
+                } else {
+                    memo.insert(entry, 0);
+                    0
+                }
+            }
+        }
+        Spring::Defective => {
+            let result = count_arrangements(
+                Entry {
+                    pattern: entry.tail(),
+                    numbers: entry.numbers.clone(),
+                },
+                memo,
+            );
+
+            memo.insert(entry, result);
+
+            result
+        }
+        Spring::Unknown => {
+            if entry.pattern.is_empty() {
+                memo.insert(entry, 1);
+                1
+            } else {
+                let mut new_pattern1 = entry.pattern.clone();
+                let mut new_pattern2 = entry.pattern.clone();
+
+                new_pattern1[0] = Spring::Working;
+                new_pattern2[0] = Spring::Defective;
+
+                let result = count_arrangements(
+                    Entry {
+                        pattern: new_pattern1,
+                        numbers: entry.numbers.clone(),
+                    },
+                    memo,
+                ) + count_arrangements(
+                    Entry {
+                        pattern: new_pattern2,
+                        numbers: entry.numbers.clone(),
+                    },
+                    memo,
+                );
+
+                memo.insert(entry, result);
+
+                result
+            }
+        }
+    }
+}
+
+pub fn part1(entries: &[Entry]) -> usize {
+    let mut memo: HashMap<Entry, usize> = HashMap::new();
+
+    entries
+        .iter()
+        .cloned()
+        .map(|entry| count_arrangements(entry, &mut memo))
+        .sum()
+}
+
+pub fn part2(entries: &[Entry]) -> usize {
+    let mut memo: HashMap<Entry, usize> = HashMap::new();
+
+    entries
+        .iter()
+        .map(|entry| count_arrangements(entry.expand(), &mut memo))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Input<'a> = Vec<Entry>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day12>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
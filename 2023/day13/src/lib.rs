@@ -0,0 +1,147 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(PartialEq, Copy, Clone)]
+pub enum Ground {
+    Ash,
+    Rock,
+}
+
+impl Ground {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Ash),
+            '#' => Some(Self::Rock),
+            _ => None,
+        }
+    }
+}
+
+type Map = Vec<Vec<Ground>>;
+
+pub fn parse(data: &str) -> Result<Vec<Map>, ParseError> {
+    let mut result: Vec<Map> = Vec::new();
+    let mut map: Map = Vec::new();
+
+    for line in data.lines() {
+        if line.is_empty() {
+            if map.is_empty() {
+                return Err(ParseError::new(line, "a pattern"));
+            }
+
+            result.push(map);
+            map = Vec::new();
+        } else {
+            parse::check_chars(line, |c| Ground::from_char(c).is_some(), "'.' or '#'")?;
+
+            if let Some(first) = map.first().filter(|first| first.len() != line.len()) {
+                return Err(ParseError::new(line, format!("{} columns", first.len())));
+            }
+
+            map.push(line.chars().filter_map(Ground::from_char).collect());
+        }
+    }
+
+    if !map.is_empty() {
+        result.push(map);
+    }
+
+    Ok(result)
+}
+
+fn count_differences(a: &[Ground], b: &[Ground]) -> usize {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| if a == b { 0 } else { 1 })
+        .sum()
+}
+
+fn count_mirror_defects_at_row(map: &Map, row: usize) -> usize {
+    (0..row)
+        .map(|index| {
+            let mirrored_index = 2 * row - index - 1;
+
+            if mirrored_index >= map.len() {
+                0
+            } else {
+                count_differences(&map[index], &map[mirrored_index])
+            }
+        })
+        .sum()
+}
+
+fn count_mirror_defects_at_col(map: &Map, col: usize) -> usize {
+    (0..col)
+        .map(|index| {
+            let mirrored_index = 2 * col - index - 1;
+
+            if mirrored_index >= map[0].len() {
+                0
+            } else {
+                let col_a: Vec<Ground> = map.iter().map(|row| row[index]).collect();
+                let col_b: Vec<Ground> = map.iter().map(|row| row[mirrored_index]).collect();
+
+                count_differences(&col_a, &col_b)
+            }
+        })
+        .sum()
+}
+
+fn calculate_result(maps: &[Map], defects: usize) -> usize {
+    maps.iter()
+        .map(|map| {
+            for row in 1..map.len() {
+                if count_mirror_defects_at_row(map, row) == defects {
+                    return row * 100;
+                }
+            }
+
+            for col in 1..map[0].len() {
+                if count_mirror_defects_at_col(map, col) == defects {
+                    return col;
+                }
+            }
+
+            0
+        })
+        .sum()
+}
+
+pub fn part1(maps: &[Map]) -> usize {
+    calculate_result(maps, 0)
+}
+
+pub fn part2(maps: &[Map]) -> usize {
+    calculate_result(maps, 1)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<Map>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day13>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
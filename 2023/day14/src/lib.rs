@@ -0,0 +1,208 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+#[derive(PartialEq, Hash, Clone)]
+pub enum Tile {
+    RoundRock,
+    RegularRock,
+    Empty,
+}
+
+#[derive(Debug)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'O' => Some(Self::RoundRock),
+            '#' => Some(Self::RegularRock),
+            '.' => Some(Self::Empty),
+            _ => None,
+        }
+    }
+}
+
+pub type Map = Vec<Vec<Tile>>;
+
+fn parse_row(line: &str) -> Result<Vec<Tile>, ParseError> {
+    parse::check_chars(line, |c| Tile::from_char(c).is_some(), "'O', '#' or '.'")?;
+
+    Ok(line.chars().filter_map(Tile::from_char).collect())
+}
+
+pub fn parse(data: &str) -> Result<Map, ParseError> {
+    let map: Map = data.lines().map(parse_row).collect::<Result<_, _>>()?;
+
+    if map.is_empty() {
+        return Err(ParseError::after(data, "a row of the platform"));
+    }
+
+    if let Some((line, _)) = data
+        .lines()
+        .zip(&map)
+        .find(|(_, row)| row.len() != map[0].len())
+    {
+        return Err(ParseError::new(line, format!("{} tiles", map[0].len())));
+    }
+
+    Ok(map)
+}
+
+fn shift_rocks(map: &mut Map, direction: Direction) {
+    while shift_rocks_step(map, &direction) {}
+}
+
+fn shift_rocks_step(map: &mut Map, direction: &Direction) -> bool {
+    let mut moved_rock = false;
+
+    match direction {
+        Direction::North => {
+            for y in 1..map.len() {
+                for x in 0..map[y].len() {
+                    moved_rock |= move_rock(map, (x, y), direction);
+                }
+            }
+        }
+        Direction::East => {
+            for y in 0..map.len() {
+                for x in (0..map[y].len() - 1).rev() {
+                    moved_rock |= move_rock(map, (x, y), direction);
+                }
+            }
+        }
+        Direction::South => {
+            for y in (0..map.len() - 1).rev() {
+                for x in 0..map[y].len() {
+                    moved_rock |= move_rock(map, (x, y), direction);
+                }
+            }
+        }
+        Direction::West => {
+            for y in 0..map.len() {
+                for x in 1..map[y].len() {
+                    moved_rock |= move_rock(map, (x, y), direction);
+                }
+            }
+        }
+    }
+
+    moved_rock
+}
+
+fn move_rock(map: &mut Map, point: (usize, usize), direction: &Direction) -> bool {
+    let target_point = match direction {
+        Direction::North => (point.0, point.1 - 1),
+        Direction::East => (point.0 + 1, point.1),
+        Direction::South => (point.0, point.1 + 1),
+        Direction::West => (point.0 - 1, point.1),
+    };
+
+    if map[point.1][point.0] == Tile::RoundRock
+        && map[target_point.1][target_point.0] == Tile::Empty
+    {
+        map[target_point.1][target_point.0] = Tile::RoundRock;
+        map[point.1][point.0] = Tile::Empty;
+
+        true
+    } else {
+        false
+    }
+}
+
+fn calculate_load(map: &Map) -> usize {
+    map.iter()
+        .enumerate()
+        .map(|(y, row)| {
+            row.iter()
+                .map(|tile| {
+                    if tile == &Tile::RoundRock {
+                        map.len() - y
+                    } else {
+                        0
+                    }
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+fn run_spin_cycle(map: &mut Map) {
+    for direction in [
+        Direction::North,
+        Direction::West,
+        Direction::South,
+        Direction::East,
+    ] {
+        shift_rocks(map, direction);
+    }
+}
+
+fn calculate_hash(map: &Map) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    map.hash(&mut hasher);
+    hasher.finish()
+}
+
+pub fn part1(map: &Map) -> usize {
+    let mut map = map.clone();
+    shift_rocks(&mut map, Direction::North);
+    calculate_load(&map)
+}
+
+pub fn part2(map: &Map) -> usize {
+    let mut map = map.clone();
+    let mut cache: Vec<(u64, usize)> = vec![(calculate_hash(&map), calculate_load(&map))];
+
+    for n in 0..1_000_000_000 {
+        run_spin_cycle(&mut map);
+        let hash = calculate_hash(&map);
+        let load = calculate_load(&map);
+
+        if let Some(start) = cache.iter().position(|&item| item == (hash, load)) {
+            let rest = (1_000_000_000 - start) % (n - start + 1);
+            return cache[rest + start].1;
+        }
+
+        cache.push((hash, load));
+    }
+
+    calculate_load(&map)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Map;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day14>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
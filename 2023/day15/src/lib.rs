@@ -0,0 +1,139 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct Lens<'a> {
+    label: &'a str,
+    focal_length: u32,
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add(u32),
+    Remove,
+}
+
+pub struct Step<'a> {
+    text: &'a str,
+    label: &'a str,
+    operation: Operation,
+}
+
+fn calculate_hash(step: &str) -> u8 {
+    step.chars()
+        .map(|c| c as u64)
+        .fold(0, |hash, c| ((hash + c) * 17) % 256) as u8
+}
+
+fn parse_step(text: &str) -> Result<Step<'_>, ParseError> {
+    let (label, operation) = if let Some((label, focal_length)) = text.split_once('=') {
+        (label, Operation::Add(parse::number(focal_length)?))
+    } else if let Some(label) = text.strip_suffix('-') {
+        (label, Operation::Remove)
+    } else {
+        return Err(ParseError::after(text, "'=' or '-'"));
+    };
+
+    Ok(Step {
+        text,
+        label,
+        operation,
+    })
+}
+
+fn sort_into_boxes<'a>(steps: &[Step<'a>]) -> HashMap<u8, Vec<Lens<'a>>> {
+    let mut boxes: HashMap<u8, Vec<Lens>> = HashMap::new();
+
+    for &Step {
+        label, operation, ..
+    } in steps
+    {
+        let hash = calculate_hash(label);
+        match operation {
+            Operation::Add(focal_length) => {
+                let lens = Lens {
+                    label,
+                    focal_length,
+                };
+
+                boxes.entry(hash).or_default();
+
+                if let Some(lenses) = boxes.get_mut(&hash) {
+                    if let Some(index) = lenses.iter().position(|l| l.label == lens.label) {
+                        lenses[index].focal_length = lens.focal_length;
+                    } else {
+                        lenses.push(lens);
+                    }
+                }
+            }
+            Operation::Remove => {
+                if let Some(lenses) = boxes.get_mut(&hash) {
+                    if let Some(index) = lenses.iter().position(|l| l.label == label) {
+                        lenses.remove(index);
+                    }
+                }
+            }
+        }
+    }
+    boxes
+}
+
+pub fn parse(data: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    data.split(',')
+        .map(|step| parse_step(step.trim()))
+        .collect()
+}
+
+pub fn part1(steps: &[Step]) -> u64 {
+    steps
+        .iter()
+        .map(|step| calculate_hash(step.text) as u64)
+        .sum()
+}
+
+pub fn part2(steps: &[Step]) -> usize {
+    let boxes = sort_into_boxes(steps);
+
+    boxes
+        .iter()
+        .map(|(&hash, lenses)| {
+            lenses
+                .iter()
+                .enumerate()
+                .map(|(index, lens)| (hash as usize + 1) * (index + 1) * lens.focal_length as usize)
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Vec<Step<'a>>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day15>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,169 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use aoc_grid::{Direction, Grid, Point};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+pub enum Tile {
+    Empty,
+    HorizontalSplitter,
+    VerticalSplitter,
+    TopLeftBottomRightMirror,
+    TopRightBottomLeftMirror,
+}
+
+impl Tile {
+    fn parse(token: &str) -> Result<Self, ParseError> {
+        match token {
+            "." => Ok(Self::Empty),
+            "-" => Ok(Self::HorizontalSplitter),
+            "|" => Ok(Self::VerticalSplitter),
+            "\\" => Ok(Self::TopLeftBottomRightMirror),
+            "/" => Ok(Self::TopRightBottomLeftMirror),
+            _ => Err(ParseError::new(token, "'.', a splitter or a mirror")),
+        }
+    }
+}
+
+pub type Map = Grid<Tile>;
+
+fn move_beam(point: &Point, direction: Direction, result: &mut Vec<(Point, Direction)>) {
+    result.push((point.step(direction), direction))
+}
+
+fn step_beam(
+    map: &Map,
+    point: Point,
+    direction: Direction,
+    energized_tiles: &mut HashSet<(Point, Direction)>,
+    queue: &mut Vec<(Point, Direction)>,
+) {
+    let Some(tile) = map.get(point) else {
+        return;
+    };
+
+    if energized_tiles.contains(&(point, direction)) {
+        return;
+    }
+
+    match tile {
+        Tile::Empty => move_beam(&point, direction, queue),
+        Tile::HorizontalSplitter => match &direction {
+            Direction::North | Direction::South => {
+                move_beam(&point, Direction::West, queue);
+                move_beam(&point, Direction::East, queue);
+            }
+            _ => move_beam(&point, direction, queue),
+        },
+        Tile::VerticalSplitter => match &direction {
+            Direction::West | Direction::East => {
+                move_beam(&point, Direction::North, queue);
+                move_beam(&point, Direction::South, queue);
+            }
+            _ => move_beam(&point, direction, queue),
+        },
+        Tile::TopLeftBottomRightMirror => {
+            let new_direction = match &direction {
+                Direction::North => Direction::West,
+                Direction::East => Direction::South,
+                Direction::South => Direction::East,
+                Direction::West => Direction::North,
+            };
+            move_beam(&point, new_direction, queue);
+        }
+        Tile::TopRightBottomLeftMirror => {
+            let new_direction = match &direction {
+                Direction::North => Direction::East,
+                Direction::East => Direction::North,
+                Direction::South => Direction::West,
+                Direction::West => Direction::South,
+            };
+            move_beam(&point, new_direction, queue);
+        }
+    }
+
+    energized_tiles.insert((point, direction));
+}
+
+fn fire_beam(map: &Map, start: (Point, Direction)) -> usize {
+    let mut queue = vec![start];
+    let mut energized_tiles: HashSet<(Point, Direction)> = HashSet::new();
+
+    while let Some((point, direction)) = queue.pop() {
+        step_beam(map, point, direction, &mut energized_tiles, &mut queue);
+    }
+
+    energized_tiles
+        .iter()
+        .map(|(point, _)| point)
+        .collect::<HashSet<&Point>>()
+        .len()
+}
+
+pub fn parse(data: &str) -> Result<Map, ParseError> {
+    let map = Grid::try_parse(data, Tile::parse)?;
+
+    if map.width() == 0 {
+        return Err(ParseError::after(data, "a row of the contraption"));
+    }
+
+    Ok(map)
+}
+
+pub fn part1(map: &Map) -> usize {
+    fire_beam(map, (Point::new(0, 0), Direction::East))
+}
+
+pub fn part2(map: &Map) -> usize {
+    let mut start_points: Vec<(Point, Direction)> = Vec::new();
+
+    let width = map.width() as i32;
+    let height = map.height() as i32;
+
+    for x in 0..width {
+        start_points.push((Point::new(x, 0), Direction::South));
+        start_points.push((Point::new(x, height - 1), Direction::South));
+    }
+
+    for y in 0..height {
+        start_points.push((Point::new(0, y), Direction::East));
+        start_points.push((Point::new(width - 1, y), Direction::West));
+    }
+
+    start_points
+        .par_iter()
+        .map(|&start_point| fire_beam(map, start_point))
+        .max()
+        .unwrap()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Map;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day16>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,108 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use aoc_grid::{Direction, Grid, Point};
+use aoc_search::dijkstra;
+
+pub type Map = Grid<u32>;
+
+struct Limits {
+    min: usize,
+    max: usize,
+}
+
+/// A crucible's position, its direction and how many blocks it has moved
+/// in that direction.
+type State = (Point, Direction, usize);
+
+fn successors(map: &Map, limits: &Limits, &(point, direction, steps): &State) -> Vec<(State, u32)> {
+    let mut directions = Vec::with_capacity(3);
+
+    if steps < limits.max {
+        directions.push(direction);
+    }
+
+    if steps >= limits.min {
+        directions.push(direction.turn_left());
+        directions.push(direction.turn_right());
+    }
+
+    directions
+        .into_iter()
+        .filter_map(|new_direction| {
+            let new_point = point.step(new_direction);
+            let heat_loss = *map.get(new_point)?;
+            let new_steps = if new_direction == direction {
+                steps + 1
+            } else {
+                1
+            };
+
+            Some(((new_point, new_direction, new_steps), heat_loss))
+        })
+        .collect()
+}
+
+fn find_lowest_heat_loss(map: &Map, limits: &Limits) -> u32 {
+    let start = Point::new(0, 0);
+    let target = Point::new(map.width() as i32 - 1, map.height() as i32 - 1);
+
+    // The crucible hasn't moved yet, so it may set off east or south.
+    let starts = [(start, Direction::East, 0), (start, Direction::South, 0)];
+
+    dijkstra(
+        starts,
+        |state| successors(map, limits, state),
+        |&(point, _, steps)| point == target && steps >= limits.min,
+    )
+    .cost()
+    .expect("No path to the target")
+}
+
+pub fn parse(data: &str) -> Result<Map, ParseError> {
+    let map = Grid::try_parse(data, parse::number)?;
+
+    if map.width() == 0 {
+        return Err(ParseError::after(data, "a row of city blocks"));
+    }
+
+    Ok(map)
+}
+
+pub fn part1(map: &Map) -> u32 {
+    find_lowest_heat_loss(map, &Limits { min: 1, max: 3 })
+}
+
+pub fn part2(map: &Map) -> u32 {
+    find_lowest_heat_loss(map, &Limits { min: 4, max: 10 })
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Map;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day17>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,430 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+#[derive(Eq, PartialEq, Debug)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+struct Instruction {
+    direction: Direction,
+    distance: usize,
+}
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+struct Wall {
+    direction: Direction,
+    start: Point,
+    end: Point,
+}
+
+struct Map {
+    walls: Vec<Wall>,
+    top_left_point: Point,
+    bottom_right_point: Point,
+}
+
+impl Direction {
+    fn parse(token: &str, color_mode: bool) -> Result<Self, ParseError> {
+        match (token, color_mode) {
+            ("U", false) | ("3", true) => Ok(Self::Up),
+            ("R", false) | ("0", true) => Ok(Self::Right),
+            ("D", false) | ("1", true) => Ok(Self::Down),
+            ("L", false) | ("2", true) => Ok(Self::Left),
+            (_, false) => Err(ParseError::new(token, "'U', 'R', 'D' or 'L'")),
+            (_, true) => Err(ParseError::new(token, "a direction from '0' to '3'")),
+        }
+    }
+}
+
+impl Instruction {
+    fn parse(line: &str) -> Result<(Self, Self), ParseError> {
+        let [direction_str, distance_str, color] = parse::words::<3>(line)?;
+        let color = parse::strip_suffix(parse::strip_prefix(color, "(#")?, ")")?;
+        let color = parse::check_chars(color, |c| c.is_ascii_hexdigit(), "a hex digit")?;
+
+        if color.len() != 6 {
+            return Err(ParseError::new(color, "six hex digits"));
+        }
+
+        let plain = Self {
+            direction: Direction::parse(direction_str, false)?,
+            distance: parse::number(distance_str)?,
+        };
+        let from_color = Self {
+            direction: Direction::parse(&color[5..], true)?,
+            distance: usize::from_str_radix(&color[..5], 16)
+                .map_err(|_| ParseError::new(&color[..5], "a hex number"))?,
+        };
+
+        Ok((plain, from_color))
+    }
+}
+
+impl Point {
+    fn from_xy(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    fn min(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        }
+    }
+
+    fn max(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+
+    fn shift_up(&self) -> Self {
+        Self {
+            x: self.x,
+            y: self.y - 1,
+        }
+    }
+
+    fn shift_right(&self) -> Self {
+        Self {
+            x: self.x + 1,
+            y: self.y,
+        }
+    }
+
+    fn shift_down(&self) -> Self {
+        Self {
+            x: self.x,
+            y: self.y + 1,
+        }
+    }
+
+    fn shift_left(&self) -> Self {
+        Self {
+            x: self.x - 1,
+            y: self.y,
+        }
+    }
+}
+
+impl Wall {
+    fn is_horizontal(&self) -> bool {
+        self.direction == Direction::Left || self.direction == Direction::Right
+    }
+
+    fn intersects_y(&self, y: i64) -> Option<RangeInclusive<i64>> {
+        if self.is_horizontal() && self.start.y == y {
+            Some(self.start.x.min(self.end.x)..=self.start.x.max(self.end.x))
+        } else if self.start.y <= y && y <= self.end.y || self.end.y <= y && y <= self.start.y {
+            Some(self.start.x..=self.end.x)
+        } else {
+            None
+        }
+    }
+}
+
+impl Map {
+    fn new() -> Self {
+        Map {
+            walls: Vec::new(),
+            top_left_point: Point::from_xy(0, 0),
+            bottom_right_point: Point::from_xy(0, 0),
+        }
+    }
+
+    fn add_wall(&mut self, wall: Wall) {
+        self.top_left_point = self.top_left_point.min(&wall.start);
+        self.top_left_point = self.top_left_point.min(&wall.end);
+        self.bottom_right_point = self.bottom_right_point.max(&wall.start);
+        self.bottom_right_point = self.bottom_right_point.max(&wall.end);
+
+        self.walls.push(wall);
+    }
+
+    fn compute_filled_area(&self) -> usize {
+        let wall_by_start = self
+            .walls
+            .iter()
+            .map(|wall| (wall.start, wall))
+            .collect::<HashMap<Point, &Wall>>();
+
+        let wall_by_end = self
+            .walls
+            .iter()
+            .map(|wall| (wall.end, wall))
+            .collect::<HashMap<Point, &Wall>>();
+
+        (self.top_left_point.y..=self.bottom_right_point.y)
+            .into_par_iter()
+            .map(|y| self.calculate_line_area(&wall_by_start, &wall_by_end, y))
+            .sum()
+    }
+
+    fn calculate_line_area(
+        &self,
+        wall_by_start: &HashMap<Point, &Wall>,
+        wall_by_end: &HashMap<Point, &Wall>,
+        y: i64,
+    ) -> usize {
+        let mut walls: Vec<&Wall> = self
+            .walls
+            .iter()
+            .filter(|wall| wall.intersects_y(y).is_some())
+            .collect();
+
+        let mut state_changes: Vec<i64> = Vec::new();
+
+        walls.sort_by_key(|a| a.start.x.min(a.end.x));
+
+        let mut skip = false;
+        for wall in &walls {
+            if skip {
+                skip = false;
+                continue;
+            }
+            if wall.is_horizontal() {
+                let is_inside = state_changes.len() % 2 == 1;
+                if wall.direction == Direction::Right {
+                    let left_wall = wall_by_end.get(&wall.start.shift_left()).unwrap();
+                    let right_wall = find_next_wall_after_horizontal(wall_by_start, wall);
+
+                    if left_wall.direction == right_wall.direction {
+                        if !is_inside {
+                            state_changes.pop();
+                            state_changes.push(wall.end.x);
+                        }
+                    } else if is_inside {
+                        state_changes.push(wall.end.x);
+                    } else {
+                        state_changes.pop();
+                    }
+                } else {
+                    // wall.direction == Direction::Left
+                    let right_wall = wall_by_end.get(&wall.start.shift_right()).unwrap();
+                    let left_wall = find_next_wall_after_horizontal(wall_by_start, wall);
+
+                    if left_wall.direction == right_wall.direction {
+                        if !is_inside {
+                            state_changes.push(wall.end.x);
+                            skip = true;
+                        }
+                    } else if !is_inside {
+                        state_changes.push(wall.end.x);
+                    } else {
+                        skip = true;
+                    }
+                }
+            } else {
+                state_changes.push(wall.start.x);
+            }
+        }
+
+        let line_area = state_changes
+            .chunks(2)
+            .map(|chunk| chunk[0].abs_diff(chunk[1]) + 1)
+            .sum::<u64>() as usize;
+        line_area
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut grid: Vec<Vec<char>> = Vec::new();
+
+        for _ in self.top_left_point.y..=self.bottom_right_point.y {
+            grid.push(
+                ['.']
+                    .repeat(self.top_left_point.x.abs_diff(self.bottom_right_point.x) as usize + 1),
+            );
+        }
+
+        for wall in &self.walls {
+            let start_point = wall.start.min(&wall.end);
+            for y in 0..=wall.start.y.abs_diff(wall.end.y) {
+                for x in 0..=wall.start.x.abs_diff(wall.end.x) {
+                    grid[(y as i64 + start_point.y - self.top_left_point.y) as usize]
+                        [(x as i64 + start_point.x - self.top_left_point.x) as usize] = '#';
+                }
+            }
+        }
+
+        for line in grid {
+            for c in line {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn execute_instruction(instruction: &Instruction, map: &mut Map, start_point: &Point) -> Point {
+    match &instruction.direction {
+        Direction::Up => {
+            let end = Point::from_xy(start_point.x, start_point.y - instruction.distance as i64);
+            map.add_wall(Wall {
+                start: Point::from_xy(start_point.x, start_point.y - 1),
+                end,
+                direction: Direction::Up,
+            });
+
+            end
+        }
+        Direction::Right => {
+            let end = Point::from_xy(start_point.x + instruction.distance as i64, start_point.y);
+            map.add_wall(Wall {
+                start: Point::from_xy(start_point.x + 1, start_point.y),
+                end,
+                direction: Direction::Right,
+            });
+            end
+        }
+        Direction::Down => {
+            let end = Point::from_xy(start_point.x, start_point.y + instruction.distance as i64);
+            map.add_wall(Wall {
+                start: Point::from_xy(start_point.x, start_point.y + 1),
+                end,
+                direction: Direction::Down,
+            });
+
+            end
+        }
+        Direction::Left => {
+            let end = Point::from_xy(start_point.x - instruction.distance as i64, start_point.y);
+            map.add_wall(Wall {
+                start: Point::from_xy(start_point.x - 1, start_point.y),
+                end,
+                direction: Direction::Left,
+            });
+
+            end
+        }
+    }
+}
+
+fn find_next_wall_after_horizontal<'a>(
+    wall_by_start: &HashMap<Point, &'a Wall>,
+    wall: &Wall,
+) -> &'a Wall {
+    if !wall.is_horizontal() {
+        panic!("Wall not horizontal!");
+    }
+    if let Some(w) = wall_by_start.get(&wall.end.shift_up()) {
+        w
+    } else if let Some(w) = wall_by_start.get(&wall.end.shift_down()) {
+        w
+    } else {
+        panic!("Hole in wall!");
+    }
+}
+
+fn dig(instructions: &[Instruction]) -> usize {
+    let mut map = Map::new();
+    let mut start_point = Point::from_xy(0, 0);
+
+    for instruction in instructions {
+        start_point = execute_instruction(instruction, &mut map, &start_point);
+    }
+
+    map.compute_filled_area()
+}
+
+/// Every instruction read both ways: as written, and from its color.
+pub struct DigPlan {
+    plain: Vec<Instruction>,
+    from_color: Vec<Instruction>,
+}
+
+/// Where the digger ends up after following `instructions` from the origin.
+fn end_point(instructions: &[Instruction]) -> Point {
+    instructions
+        .iter()
+        .fold(Point::from_xy(0, 0), |point, instruction| {
+            let distance = instruction.distance as i64;
+
+            match instruction.direction {
+                Direction::Up => Point::from_xy(point.x, point.y - distance),
+                Direction::Right => Point::from_xy(point.x + distance, point.y),
+                Direction::Down => Point::from_xy(point.x, point.y + distance),
+                Direction::Left => Point::from_xy(point.x - distance, point.y),
+            }
+        })
+}
+
+pub fn parse(data: &str) -> Result<DigPlan, ParseError> {
+    let (plain, from_color): (Vec<_>, Vec<_>) = data
+        .lines()
+        .map(Instruction::parse)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+
+    let Some(last_line) = data.lines().last() else {
+        return Err(ParseError::after(data, "a dig instruction"));
+    };
+
+    // Both readings of the plan have to dig a closed loop.
+    let origin = Point::from_xy(0, 0);
+    if end_point(&plain) != origin || end_point(&from_color) != origin {
+        return Err(ParseError::new(
+            last_line,
+            "an instruction that returns to the start",
+        ));
+    }
+
+    Ok(DigPlan { plain, from_color })
+}
+
+pub fn part1(plan: &DigPlan) -> usize {
+    dig(&plan.plain)
+}
+
+pub fn part2(plan: &DigPlan) -> usize {
+    dig(&plan.from_color)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    type Input<'a> = DigPlan;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day18>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
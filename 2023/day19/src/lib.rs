@@ -0,0 +1,405 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+// `Result` below is a workflow's outcome.
+type ParseResult<T> = std::result::Result<T, ParseError>;
+
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    fn parse(data: &str) -> ParseResult<Self> {
+        match data {
+            "x" => Ok(Self::X),
+            "m" => Ok(Self::M),
+            "a" => Ok(Self::A),
+            "s" => Ok(Self::S),
+            _ => Err(ParseError::new(data, "'x', 'm', 'a' or 's'")),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Result {
+    Accepted,
+    Rejected,
+    Send(String),
+}
+
+impl Result {
+    fn from_str(data: &str) -> Self {
+        match data {
+            "A" => Self::Accepted,
+            "R" => Self::Rejected,
+            label => Self::Send(label.into()),
+        }
+    }
+}
+
+enum FinalResult {
+    Accepted,
+    Rejected,
+}
+
+impl FinalResult {
+    fn from_result(result: &Result) -> Self {
+        match result {
+            Result::Accepted => Self::Accepted,
+            Result::Rejected => Self::Rejected,
+            Result::Send(_) => panic!("Result is not final"),
+        }
+    }
+}
+
+enum WorkflowStep {
+    Bigger(Category, u64, Result),
+    Smaller(Category, u64, Result),
+    Just(Result),
+}
+
+impl WorkflowStep {
+    fn parse(data: &str) -> ParseResult<Self> {
+        if let Some((condition_str, result_str)) = data.split_once(':') {
+            let result = Result::from_str(result_str);
+
+            if let Some((category_str, value_str)) = condition_str.split_once('<') {
+                Ok(Self::Smaller(
+                    Category::parse(category_str)?,
+                    parse::number(value_str)?,
+                    result,
+                ))
+            } else if let Some((category_str, value_str)) = condition_str.split_once('>') {
+                Ok(Self::Bigger(
+                    Category::parse(category_str)?,
+                    parse::number(value_str)?,
+                    result,
+                ))
+            } else {
+                Err(ParseError::new(
+                    condition_str,
+                    "a condition with '<' or '>'",
+                ))
+            }
+        } else {
+            Ok(Self::Just(Result::from_str(data)))
+        }
+    }
+}
+
+type Workflow = Vec<WorkflowStep>;
+type WorkflowMap = HashMap<String, Workflow>;
+
+#[derive(Clone, Debug)]
+struct Item {
+    x: u64,
+    m: u64,
+    a: u64,
+    s: u64,
+}
+
+impl Item {
+    fn new_min() -> Self {
+        Self {
+            x: 0,
+            m: 0,
+            a: 0,
+            s: 0,
+        }
+    }
+
+    fn new_max() -> Self {
+        Self {
+            x: 4001,
+            m: 4001,
+            a: 4001,
+            s: 4001,
+        }
+    }
+
+    fn set(&mut self, cat: &Category, value: u64) {
+        match cat {
+            Category::X => self.x = value,
+            Category::M => self.m = value,
+            Category::A => self.a = value,
+            Category::S => self.s = value,
+        }
+    }
+
+    fn get(&self, cat: &Category) -> u64 {
+        match cat {
+            Category::X => self.x,
+            Category::M => self.m,
+            Category::A => self.a,
+            Category::S => self.s,
+        }
+    }
+
+    fn parse(data: &str) -> ParseResult<Self> {
+        let data = parse::strip_suffix(parse::strip_prefix(data, "{")?, "}")?;
+        let mut new_item = Item {
+            x: 0,
+            m: 0,
+            a: 0,
+            s: 0,
+        };
+
+        for data_item_string in data.split(',') {
+            let (category_str, value_str) = parse::split_once(data_item_string, "=")?;
+            new_item.set(&Category::parse(category_str)?, parse::number(value_str)?);
+        }
+
+        Ok(new_item)
+    }
+
+    fn sum(&self) -> u64 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ItemRange {
+    min: Item,
+    max: Item,
+}
+
+impl ItemRange {
+    fn new() -> Self {
+        Self {
+            min: Item::new_min(),
+            max: Item::new_max(),
+        }
+    }
+
+    fn apply(&mut self, step: &WorkflowStep) {
+        match step {
+            WorkflowStep::Bigger(cat, value, _) => {
+                self.min.set(cat, self.min.get(cat).max(*value));
+            }
+            WorkflowStep::Smaller(cat, value, _) => {
+                self.max.set(cat, self.max.get(cat).min(*value));
+            }
+            WorkflowStep::Just(_) => {}
+        }
+    }
+
+    fn apply_inverse(&mut self, step: &WorkflowStep) {
+        match step {
+            WorkflowStep::Bigger(cat, value, _) => {
+                self.max.set(cat, self.max.get(cat).min(*value + 1));
+            }
+            WorkflowStep::Smaller(cat, value, _) => {
+                self.min.set(cat, self.min.get(cat).max(*value - 1));
+            }
+            WorkflowStep::Just(_) => {}
+        }
+    }
+}
+
+fn parse_workflows(data: &str) -> ParseResult<WorkflowMap> {
+    let mut result: HashMap<String, Workflow> = HashMap::new();
+    let mut targets: Vec<&str> = Vec::new();
+
+    for line in data.lines() {
+        let (label, rest) = parse::split_once(line, "{")?;
+        let steps_str = parse::strip_suffix(rest, "}")?;
+
+        let workflow: Workflow = steps_str
+            .split(',')
+            .map(WorkflowStep::parse)
+            .collect::<ParseResult<_>>()?;
+
+        if !matches!(workflow.last(), Some(WorkflowStep::Just(_))) {
+            return Err(ParseError::after(
+                steps_str,
+                "a final step without a condition",
+            ));
+        }
+
+        targets.extend(steps_str.split(',').map(|step| match step.split_once(':') {
+            Some((_, target)) => target,
+            None => step,
+        }));
+        result.insert(label.into(), workflow);
+    }
+
+    if !result.contains_key("in") {
+        return Err(ParseError::after(data, "a workflow named 'in'"));
+    }
+
+    if let Some(target) = targets
+        .into_iter()
+        .find(|&target| target != "A" && target != "R" && !result.contains_key(target))
+    {
+        return Err(ParseError::new(target, "a workflow that exists"));
+    }
+
+    Ok(result)
+}
+
+fn parse_items(data: &str) -> ParseResult<Vec<Item>> {
+    data.lines().map(Item::parse).collect()
+}
+
+fn parse_input(data: &str) -> ParseResult<(WorkflowMap, Vec<Item>)> {
+    let data = data.trim();
+    let (workflow_str, item_str) = data
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::after(data, "a blank line before the parts"))?;
+
+    Ok((parse_workflows(workflow_str)?, parse_items(item_str)?))
+}
+
+fn execute_workflow(workflow: &Workflow, item: &Item) -> Result {
+    for step in workflow {
+        match step {
+            WorkflowStep::Bigger(cat, value, result) => {
+                if item.get(cat) > *value {
+                    return result.clone();
+                }
+            }
+            WorkflowStep::Smaller(cat, value, result) => {
+                if item.get(cat) < *value {
+                    return result.clone();
+                }
+            }
+            WorkflowStep::Just(result) => {
+                return result.clone();
+            }
+        }
+    }
+
+    panic!("Workflow did not finish");
+}
+
+fn process_item(workflows: &WorkflowMap, item: &Item) -> FinalResult {
+    let mut workflow_label = String::from("in");
+    loop {
+        let workflow = workflows.get(&workflow_label).expect("Unknown workflow");
+        let result = execute_workflow(workflow, item);
+
+        match result {
+            Result::Send(new_label) => workflow_label = new_label,
+            result => {
+                return FinalResult::from_result(&result);
+            }
+        }
+    }
+}
+
+fn find_paths(
+    label: &str,
+    workflows: &WorkflowMap,
+    mut current_limits: ItemRange,
+) -> Vec<ItemRange> {
+    let workflow = workflows.get(label).unwrap();
+    let mut ranges: Vec<ItemRange> = Vec::new();
+
+    for step in workflow {
+        match step {
+            WorkflowStep::Just(Result::Accepted) => {
+                ranges.push(current_limits.clone());
+            }
+            WorkflowStep::Just(Result::Rejected) => {}
+            WorkflowStep::Just(Result::Send(label)) => {
+                let mut new_ranges = find_paths(label, workflows, current_limits.clone());
+                ranges.append(&mut new_ranges);
+            }
+            WorkflowStep::Bigger(_, _, result) | WorkflowStep::Smaller(_, _, result) => {
+                let mut new_limit = current_limits.clone();
+                new_limit.apply(step);
+                match result {
+                    Result::Accepted => ranges.push(new_limit),
+                    Result::Rejected => {}
+                    Result::Send(label) => {
+                        let mut new_ranges = find_paths(label, workflows, new_limit);
+                        ranges.append(&mut new_ranges);
+                    }
+                }
+
+                current_limits.apply_inverse(step);
+            }
+        }
+    }
+
+    ranges
+}
+
+fn count_valid_combinations(ranges: &Vec<ItemRange>) -> u64 {
+    let mut result = 0;
+
+    for range in ranges {
+        if range.max.x > range.min.x
+            && range.max.m > range.min.m
+            && range.max.a > range.min.a
+            && range.max.s > range.min.s
+        {
+            result += (range.max.x - range.min.x - 1)
+                * (range.max.m - range.min.m - 1)
+                * (range.max.a - range.min.a - 1)
+                * (range.max.s - range.min.s - 1);
+        }
+    }
+
+    result
+}
+
+pub struct Input {
+    workflows: WorkflowMap,
+    items: Vec<Item>,
+}
+
+pub fn parse(data: &str) -> ParseResult<Input> {
+    let (workflows, items) = parse_input(data)?;
+    Ok(Input { workflows, items })
+}
+
+pub fn part1(input: &Input) -> u64 {
+    input
+        .items
+        .iter()
+        .filter(|item| matches!(process_item(&input.workflows, item), FinalResult::Accepted))
+        .map(|item| item.sum())
+        .sum()
+}
+
+pub fn part2(input: &Input) -> u64 {
+    let paths = find_paths("in", &input.workflows, ItemRange::new());
+
+    count_valid_combinations(&paths)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    type Input<'a> = Input;
+
+    fn parse(data: &str) -> ParseResult<Self::Input<'_>> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day19>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
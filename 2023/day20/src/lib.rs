@@ -0,0 +1,230 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use aoc_math::{crt, next_congruent};
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Signal {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FlipFlopState {
+    On,
+    Off,
+}
+
+#[derive(Debug, Clone)]
+enum Module {
+    Broadcaster,
+    FlipFlop(FlipFlopState),
+    Conjunction(HashMap<String, Signal>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Connection {
+    module: Module,
+    outputs: Vec<String>,
+}
+
+pub type Connections = HashMap<String, Connection>;
+
+pub fn parse(data: &str) -> Result<Connections, ParseError> {
+    let mut connections: Connections = HashMap::new();
+    let mut conjunction_labels: Vec<&str> = Vec::new();
+
+    for line in data.lines() {
+        let (module_str, output_str) = parse::split_once(line, " -> ")?;
+
+        let (label, module) = if let Some(label) = module_str.strip_prefix('%') {
+            (label, Module::FlipFlop(FlipFlopState::Off))
+        } else if let Some(label) = module_str.strip_prefix('&') {
+            conjunction_labels.push(label);
+            (label, Module::Conjunction(HashMap::new()))
+        } else {
+            (module_str, Module::Broadcaster)
+        };
+
+        parse::check_chars(label, |c| c.is_ascii_lowercase(), "a module name")?;
+        if label.is_empty() {
+            return Err(ParseError::new(module_str, "a module name"));
+        }
+
+        let outputs: Vec<String> = output_str.split(", ").map(|s| s.into()).collect();
+
+        connections.insert(label.into(), Connection { module, outputs });
+    }
+
+    for label in conjunction_labels {
+        let input_label = find_inputs(&connections, label);
+
+        let conjunction = connections.get_mut(label).unwrap();
+        if let Module::Conjunction(inputs) = &mut conjunction.module {
+            for input in input_label {
+                inputs.insert(input.clone(), Signal::Low);
+            }
+        }
+    }
+
+    Ok(connections)
+}
+
+fn find_inputs(connections: &Connections, label: &str) -> Vec<String> {
+    let input_label: Vec<String> = connections
+        .iter()
+        .filter_map(|(l, c)| {
+            if c.outputs.contains(&label.into()) {
+                Some(l.clone())
+            } else {
+                None
+            }
+        })
+        .collect();
+    input_label
+}
+
+fn send_signal(
+    sender: &str,
+    receivers: &Vec<String>,
+    signal: Signal,
+    queue: &mut VecDeque<(String, Signal, String)>,
+) {
+    for receiver in receivers {
+        queue.push_back((sender.into(), signal, receiver.clone()));
+    }
+}
+
+fn process_signal(
+    sender: &str,
+    receiver: &str,
+    signal: Signal,
+    connection: &mut Connection,
+    queue: &mut VecDeque<(String, Signal, String)>,
+) {
+    match &mut connection.module {
+        Module::Broadcaster => {
+            send_signal(receiver, &connection.outputs, signal, queue);
+        }
+        Module::FlipFlop(state) => {
+            if signal == Signal::Low {
+                if state == &FlipFlopState::Off {
+                    send_signal(receiver, &connection.outputs, Signal::High, queue);
+                    connection.module = Module::FlipFlop(FlipFlopState::On);
+                } else {
+                    send_signal(receiver, &connection.outputs, Signal::Low, queue);
+                    connection.module = Module::FlipFlop(FlipFlopState::Off);
+                }
+            }
+        }
+        Module::Conjunction(state) => {
+            state.insert(sender.into(), signal);
+
+            if state.iter().all(|(_, signal)| signal == &Signal::High) {
+                send_signal(receiver, &connection.outputs, Signal::Low, queue);
+            } else {
+                send_signal(receiver, &connection.outputs, Signal::High, queue);
+            }
+        }
+    }
+}
+
+pub fn part1(connections: &Connections) -> i32 {
+    let mut connections = connections.clone();
+    let mut queue: VecDeque<(String, Signal, String)> = VecDeque::new();
+    let mut low_counter = 0;
+    let mut high_counter = 0;
+
+    for _ in 0..1000 {
+        queue.push_back(("button".into(), Signal::Low, "broadcaster".into()));
+
+        while let Some((sender, signal, receiver)) = queue.pop_front() {
+            match signal {
+                Signal::Low => low_counter += 1,
+                Signal::High => high_counter += 1,
+            }
+
+            if let Some(connection) = connections.get_mut(&receiver) {
+                process_signal(&sender, &receiver, signal, connection, &mut queue);
+            }
+        }
+    }
+
+    low_counter * high_counter
+}
+
+pub fn part2(connections: &Connections) -> u64 {
+    let mut connections = connections.clone();
+    let mut queue: VecDeque<(String, Signal, String)> = VecDeque::new();
+    let rx_inputs: Vec<String> = find_inputs(&connections, "rx")
+        .iter()
+        .flat_map(|l| find_inputs(&connections, l))
+        .collect();
+    // The button presses at which each input first sent a high pulse, and
+    // the first press after that at which it did again.
+    let mut rx_input_presses: HashMap<String, Vec<i64>> = HashMap::new();
+
+    for button_press in 1.. {
+        queue.push_back(("button".into(), Signal::Low, "broadcaster".into()));
+
+        while let Some((sender, signal, receiver)) = queue.pop_front() {
+            if signal == Signal::High && rx_inputs.contains(&sender) {
+                let presses = rx_input_presses.entry(sender.clone()).or_default();
+                if presses.len() < 2 && presses.last() != Some(&button_press) {
+                    presses.push(button_press);
+                }
+            }
+
+            if let Some(connection) = connections.get_mut(&receiver) {
+                process_signal(&sender, &receiver, signal, connection, &mut queue);
+            }
+        }
+
+        if rx_input_presses.len() == rx_inputs.len()
+            && rx_input_presses.values().all(|presses| presses.len() == 2)
+        {
+            break;
+        }
+    }
+
+    // Each input repeats every `second - first` presses, so rx gets a low
+    // pulse once all of them line up.
+    let congruences = rx_input_presses
+        .values()
+        .map(|presses| (presses[0], presses[1] - presses[0]));
+    let (press, period) = crt(congruences).expect("The inputs of rx never line up");
+    let first_press = rx_input_presses.values().map(|presses| presses[0]).max();
+
+    next_congruent(press, period, first_press.unwrap_or(1)) as u64
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    type Input<'a> = Connections;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day20>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
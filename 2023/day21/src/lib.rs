@@ -0,0 +1,242 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Params, Solution};
+use aoc_grid::{Grid, Point};
+use std::collections::HashSet;
+
+#[derive(PartialEq)]
+pub enum Tile {
+    GardenPlot,
+    Rock,
+}
+
+impl Tile {
+    fn check(token: &str) -> Result<&str, ParseError> {
+        match token {
+            "." | "S" | "#" => Ok(token),
+            _ => Err(ParseError::new(token, "'.', '#' or 'S'")),
+        }
+    }
+
+    fn from_token(token: &str) -> Self {
+        match token {
+            "#" => Self::Rock,
+            _ => Self::GardenPlot,
+        }
+    }
+}
+
+pub type Map = Grid<Tile>;
+
+/// Walking the endless map up to this many steps takes well under a second.
+pub const BRUTE_FORCE_STEPS: usize = 1000;
+
+fn parse_input(data: &str) -> Result<(Map, Point), ParseError> {
+    let tokens = Grid::try_parse(data, Tile::check)?;
+    let start_point = tokens
+        .position(|&token| token == "S")
+        .ok_or_else(|| ParseError::after(data, "a start tile 'S'"))?;
+
+    Ok((tokens.map(|&token| Tile::from_token(token)), start_point))
+}
+
+fn make_step(start_point: &Point, map: &Map, queue: &mut HashSet<Point>) {
+    for point in map.neighbors4(*start_point) {
+        if map[point] == Tile::GardenPlot {
+            queue.insert(point);
+        }
+    }
+}
+
+pub fn parse(data: &str) -> Result<(Map, Point), ParseError> {
+    parse_input(data)
+}
+
+pub fn part1(map: &Map, start_point: &Point, steps: usize) -> usize {
+    let mut queue: HashSet<Point> = HashSet::new();
+    queue.insert(*start_point);
+
+    for _ in 0..steps {
+        let mut next_queue: HashSet<Point> = HashSet::new();
+
+        for point in queue {
+            make_step(&point, map, &mut next_queue);
+        }
+
+        queue = next_queue;
+    }
+
+    queue.len()
+}
+
+/// Counts the plots reachable in exactly each of `steps` steps on the map
+/// repeated endlessly in every direction, by walking it step by step.
+pub fn count_plots(map: &Map, start_point: &Point, steps: &[usize]) -> Vec<usize> {
+    let max_steps = steps.iter().copied().max().unwrap_or(0);
+
+    // A plot reached in `d` steps can be reached again in `d + 2` by stepping
+    // back and forth, so it counts for every later step count of the same parity.
+    let mut reached_per_step = vec![1];
+    let mut visited: HashSet<Point> = HashSet::from([*start_point]);
+    let mut frontier = vec![*start_point];
+
+    for _ in 0..max_steps {
+        let mut next_frontier = Vec::new();
+
+        for point in frontier {
+            for next_point in point.neighbors4() {
+                if *map.get_wrapped(next_point) == Tile::GardenPlot && visited.insert(next_point) {
+                    next_frontier.push(next_point);
+                }
+            }
+        }
+
+        reached_per_step.push(next_frontier.len());
+        frontier = next_frontier;
+    }
+
+    steps
+        .iter()
+        .map(|&steps| {
+            reached_per_step[..=steps]
+                .iter()
+                .skip(steps % 2)
+                .step_by(2)
+                .sum()
+        })
+        .collect()
+}
+
+/// Extrapolates the plots reachable on the endless map in `steps` steps.
+///
+/// The start is in the middle of a square map with its row and column free of
+/// rocks, so every `size` steps the reachable area grows by one more ring of
+/// whole maps and the count is a quadratic in the number of rings. Returns
+/// `None` for a map without that shape.
+pub fn extrapolate(map: &Map, start_point: &Point, steps: usize) -> Option<usize> {
+    let size = map.width();
+    let center = (size / 2) as i32;
+
+    let is_open = |point: Point| map[point] == Tile::GardenPlot;
+    if map.height() != size
+        || *start_point != Point::new(center, center)
+        || !(0..size as i32)
+            .all(|i| is_open(Point::new(i, center)) && is_open(Point::new(center, i)))
+    {
+        return None;
+    }
+
+    let offset = steps % size;
+    let rings = steps / size;
+    let samples = count_plots(
+        map,
+        start_point,
+        &[offset, offset + size, offset + 2 * size],
+    );
+    let [a, b, c] = [samples[0], samples[1], samples[2]];
+
+    // Newton's forward differences through the three samples.
+    let first = b - a;
+    let second = c + a - 2 * b;
+
+    Some(a + rings * first + rings * rings.saturating_sub(1) / 2 * second)
+}
+
+/// The plots reachable on the endless map, walked step by step for step
+/// counts that are small enough and extrapolated otherwise.
+pub fn part2(map: &Map, start_point: &Point, steps: usize) -> Option<usize> {
+    if steps <= BRUTE_FORCE_STEPS {
+        return Some(count_plots(map, start_point, &[steps])[0]);
+    }
+
+    extrapolate(map, start_point, steps)
+}
+
+pub struct Day21;
+
+pub struct Garden {
+    map: Map,
+    start: Point,
+    steps: usize,
+    long_steps: usize,
+}
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+
+    type Input<'a> = Garden;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(data, &Params::default())
+    }
+
+    fn parse_with<'a>(data: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let (map, start) = parse(data)?;
+
+        Ok(Garden {
+            map,
+            start,
+            steps: params.get("steps", 64) as usize,
+            long_steps: params.get("long_steps", 26_501_365) as usize,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input.map, &input.start, input.steps).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input.map, &input.start, input.long_steps).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day21>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_count_plots() {
+        let data = include_str!("../fixtures/example.txt");
+        let (map, start_point) = parse(data).unwrap();
+
+        assert_eq!(
+            vec![16, 50, 1594, 6536],
+            count_plots(&map, &start_point, &[6, 10, 50, 100])
+        );
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let data = "\
+...........
+.#......##.
+..#.#....#.
+.#.....#...
+......#..#.
+.....S.....
+.##.#...#..
+..#....#.#.
+...#..#....
+.#.....##..
+...........
+";
+        let (map, start_point) = parse(data).unwrap();
+
+        for steps in [5, 27, 50, 77, 123] {
+            assert_eq!(
+                count_plots(&map, &start_point, &[steps])[0],
+                extrapolate(&map, &start_point, steps).unwrap(),
+                "{} steps",
+                steps
+            );
+        }
+
+        let (map, start_point) = parse(include_str!("../fixtures/example.txt")).unwrap();
+        assert_eq!(None, extrapolate(&map, &start_point, 5000));
+    }
+}
@@ -1,147 +1,37 @@
-use std::collections::HashSet;
-use std::fs;
+use aoc2023_day21::{count_plots, extrapolate, parse, part1, part2};
+use aoc_common::input;
 
-#[derive(PartialEq)]
-enum Tile {
-    GardenPlot,
-    Rock,
-}
-
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-#[derive(Debug, Hash, Copy, Clone, Eq, PartialEq)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl Point {
-    fn from_xy(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
-}
-
-struct Map {
-    tiles: Vec<Vec<Tile>>,
-    width: usize,
-    height: usize,
-}
+const STEPS: usize = 26_501_365;
 
-impl Map {
-    fn get(&self, point: &Point) -> Option<&Tile> {
-        if point.x < self.width && point.y < self.height {
-            Some(&self.tiles[point.y][point.x])
-        } else {
-            None
-        }
-    }
-
-    fn go(&self, point: &Point, direction: &Direction) -> Option<Point> {
-        let new_point = match direction {
-            Direction::North => {
-                if point.y > 0 {
-                    Some(Point::from_xy(point.x, point.y - 1))
-                } else {
-                    None
-                }
-            }
-            Direction::East => {
-                if point.x < self.width - 1 {
-                    Some(Point::from_xy(point.x + 1, point.y))
-                } else {
-                    None
-                }
-            }
-            Direction::South => {
-                if point.y < self.height - 1 {
-                    Some(Point::from_xy(point.x, point.y + 1))
-                } else {
-                    None
-                }
-            }
-            Direction::West => {
-                if point.x > 0 {
-                    Some(Point::from_xy(point.x - 1, point.y))
-                } else {
-                    None
-                }
-            }
-        };
+fn main() {
+    let data = input::load_or_exit(2023, 21);
+    let (map, start_point) = input::parse_or_exit(&data, parse);
 
-        new_point.filter(|p| self.get(p) == Some(&Tile::GardenPlot))
-    }
-}
+    // `--check` walks a few rings of maps step by step and compares the
+    // result with the extrapolation.
+    if input::flag("check") {
+        let size = map.width();
 
-fn parse_input(data: &str) -> (Map, Point) {
-    let mut tiles: Vec<Vec<Tile>> = Vec::new();
-    let mut start_point = Point { x: 0, y: 0 };
+        for rings in 3..=5 {
+            let steps = STEPS % size + rings * size;
+            let walked = count_plots(&map, &start_point, &[steps])[0];
 
-    for (y, line) in data.lines().enumerate() {
-        let mut row: Vec<Tile> = Vec::new();
-        for (x, c) in line.chars().enumerate() {
-            match c {
-                '.' => row.push(Tile::GardenPlot),
-                '#' => row.push(Tile::Rock),
-                'S' => {
-                    row.push(Tile::GardenPlot);
-                    start_point.x = x;
-                    start_point.y = y;
+            match extrapolate(&map, &start_point, steps) {
+                Some(extrapolated) if extrapolated == walked => {
+                    println!("{} steps: {} plots", steps, walked)
                 }
-                _ => panic!("Unknown tile {}", c),
+                extrapolated => println!(
+                    "{} steps: walked {} plots, extrapolated {:?}",
+                    steps, walked, extrapolated
+                ),
             }
         }
-        tiles.push(row);
     }
 
-    let width = tiles[0].len();
-    let height = tiles.len();
+    println!("Day 21 Part 1: {}", part1(&map, &start_point, 64));
 
-    (
-        Map {
-            tiles,
-            width,
-            height,
-        },
-        start_point,
-    )
-}
-
-fn make_step(start_point: &Point, map: &Map, queue: &mut HashSet<Point>) {
-    for direction in [
-        Direction::North,
-        Direction::East,
-        Direction::South,
-        Direction::West,
-    ] {
-        if let Some(point) = map.go(start_point, &direction) {
-            queue.insert(point);
-        }
+    match part2(&map, &start_point, STEPS) {
+        Some(plots) => println!("Day 21 Part 2: {}", plots),
+        None => println!("Day 21 Part 2: the map can't be extrapolated"),
     }
 }
-
-fn main() {
-    let data = fs::read_to_string("day21.txt").expect("Can't read input file");
-    let (map, start_point) = parse_input(&data);
-
-    let mut queue: HashSet<Point> = HashSet::new();
-    queue.insert(start_point);
-
-    for _ in 0..64 {
-        let mut next_queue: HashSet<Point> = HashSet::new();
-
-        for point in queue {
-            make_step(&point, &map, &mut next_queue);
-        }
-
-        queue = next_queue;
-    }
-
-    let part1_result = queue.iter().copied().collect::<HashSet<Point>>().len();
-
-    println!("Day 21 Part 1: {}", part1_result);
-}
@@ -0,0 +1,312 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use aoc_grid::Point3;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+const DOWN: Point3 = Point3::new(0, 0, -1);
+const UP: Point3 = Point3::new(0, 0, 1);
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Brick {
+    start: Point3,
+    end: Point3,
+    blocks: Vec<Point3>,
+}
+
+impl PartialOrd<Self> for Brick {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Brick {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.start
+            .z
+            .min(self.end.z)
+            .cmp(&other.start.z.min(other.end.z))
+    }
+}
+
+impl Brick {
+    fn from(start: Point3, end: Point3) -> Self {
+        let blocks = Self::create_blocks(&start, &end);
+
+        Self { start, end, blocks }
+    }
+
+    fn create_blocks(start: &Point3, end: &Point3) -> Vec<Point3> {
+        let mut blocks = Vec::new();
+
+        for z in start.z.min(end.z)..=start.z.max(end.z) {
+            for y in start.y.min(end.y)..=start.y.max(end.y) {
+                for x in start.x.min(end.x)..=start.x.max(end.x) {
+                    blocks.push(Point3::new(x, y, z));
+                }
+            }
+        }
+
+        blocks
+    }
+
+    fn move_by(&mut self, delta: Point3) {
+        self.start = self.start + delta;
+        self.end = self.end + delta;
+
+        for block in &mut self.blocks {
+            *block = *block + delta;
+        }
+    }
+}
+
+pub struct Map {
+    bricks: Vec<Brick>,
+    map: Vec<Vec<Vec<HashSet<usize>>>>,
+}
+
+impl Map {
+    fn from(mut bricks: Vec<Brick>) -> Map {
+        let dimensions = bricks.iter().fold(Point3::default(), |acc, p| {
+            acc.max_each(p.start).max_each(p.end)
+        });
+
+        let mut map: Vec<Vec<Vec<HashSet<usize>>>> = Vec::new();
+
+        for _ in 0..=dimensions.z {
+            let mut z_vec: Vec<Vec<HashSet<usize>>> = Vec::new();
+            for _ in 0..=dimensions.y {
+                let mut y_vec: Vec<HashSet<usize>> = Vec::new();
+                for _ in 0..=dimensions.x {
+                    y_vec.push(HashSet::new());
+                }
+                z_vec.push(y_vec);
+            }
+            map.push(z_vec);
+        }
+
+        bricks.sort();
+
+        for (index, brick) in bricks.iter().enumerate() {
+            for block in &brick.blocks {
+                map[block.z as usize][block.y as usize][block.x as usize].insert(index);
+            }
+        }
+
+        Map { bricks, map }
+    }
+
+    fn cell(&self, point: Point3) -> &HashSet<usize> {
+        &self.map[point.z as usize][point.y as usize][point.x as usize]
+    }
+
+    fn cell_mut(&mut self, point: Point3) -> &mut HashSet<usize> {
+        &mut self.map[point.z as usize][point.y as usize][point.x as usize]
+    }
+
+    /// Lets every brick fall as far as it can, lowest first, and moves it in
+    /// the map once it has landed.
+    fn condense(&mut self) {
+        for index in 0..self.bricks.len() {
+            let mut fall = Point3::default();
+            while self.can_fall(index, fall + DOWN) {
+                fall = fall + DOWN;
+            }
+
+            if fall == Point3::default() {
+                continue;
+            }
+
+            for block_index in 0..self.bricks[index].blocks.len() {
+                let block = self.bricks[index].blocks[block_index];
+                self.cell_mut(block).remove(&index);
+            }
+
+            self.bricks[index].move_by(fall);
+
+            for block_index in 0..self.bricks[index].blocks.len() {
+                let block = self.bricks[index].blocks[block_index];
+                self.cell_mut(block).insert(index);
+            }
+        }
+    }
+
+    /// Whether the brick at `index` could move by `fall` without leaving the
+    /// ground or running into another brick.
+    fn can_fall(&self, index: usize, fall: Point3) -> bool {
+        self.bricks[index].blocks.iter().all(|&block| {
+            let block = block + fall;
+
+            block.z >= 1 && self.cell(block).iter().all(|&i| i == index)
+        })
+    }
+
+    fn supports_map(&self) -> Vec<HashSet<usize>> {
+        let supports = |block: &Point3, brick_index: usize| {
+            self.cell(*block + UP)
+                .iter()
+                .filter(move |&index| *index != brick_index)
+        };
+
+        self.bricks
+            .iter()
+            .enumerate()
+            .map(|(index, brick)| {
+                brick
+                    .blocks
+                    .iter()
+                    .flat_map(|brick| supports(brick, index))
+                    .copied()
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn supported_by_map(&self) -> Vec<HashSet<usize>> {
+        let supported_by = |block: &Point3, brick_index: usize| {
+            self.cell(*block + DOWN)
+                .iter()
+                .filter(move |&index| *index != brick_index)
+        };
+
+        self.bricks
+            .iter()
+            .enumerate()
+            .map(|(index, brick)| {
+                brick
+                    .blocks
+                    .iter()
+                    .flat_map(|brick| supported_by(brick, index))
+                    .copied()
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn can_be_disintegrated(
+        &self,
+        brick_index: usize,
+        supported_map: &[HashSet<usize>],
+        supported_by_map: &[HashSet<usize>],
+    ) -> bool {
+        supported_map[brick_index].iter().all(|&index| {
+            supported_by_map[index]
+                .iter()
+                .filter(|i| **i != brick_index)
+                .count()
+                > 0
+        })
+    }
+
+    fn count_falling_bricks(
+        &self,
+        brick_index: usize,
+        supported_by_map: &[HashSet<usize>],
+    ) -> usize {
+        let mut falling_bricks = 0;
+        let mut ignored_indices = vec![brick_index];
+
+        let min_z = self.bricks[brick_index]
+            .start
+            .z
+            .min(self.bricks[brick_index].end.z);
+
+        for (index, _) in self
+            .bricks
+            .iter()
+            .enumerate()
+            .filter(|(_, brick)| brick.start.z.min(brick.end.z) > min_z)
+        {
+            if index == brick_index {
+                continue;
+            }
+
+            let count: HashSet<&usize> = supported_by_map[index]
+                .iter()
+                .filter(|i| !ignored_indices.contains(i))
+                .collect();
+
+            if count.is_empty() {
+                falling_bricks += 1;
+                ignored_indices.push(index);
+            }
+        }
+
+        falling_bricks
+    }
+}
+
+fn parse_input(data: &str) -> Result<Vec<Brick>, ParseError> {
+    data.lines()
+        .map(|line| {
+            let (start, end) = parse::split_once(line, "~")?;
+
+            let [start, end] = [start, end].map(|point| match Point3::parse(point) {
+                Ok(parsed) if parsed.min_each(Point3::default()) != Point3::default() => {
+                    Err(ParseError::new(point, "non-negative coordinates"))
+                }
+                parsed => parsed,
+            });
+
+            Ok(Brick::from(start?, end?))
+        })
+        .collect()
+}
+
+pub fn parse(data: &str) -> Result<Map, ParseError> {
+    let mut map = Map::from(parse_input(data)?);
+    map.condense();
+    Ok(map)
+}
+
+pub fn part1(map: &Map) -> usize {
+    let supports_map = map.supports_map();
+    let supported_by_map = map.supported_by_map();
+
+    map.bricks
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| map.can_be_disintegrated(*index, &supports_map, &supported_by_map))
+        .count()
+}
+
+pub fn part2(map: &Map) -> usize {
+    let supported_by_map = map.supported_by_map();
+
+    map.bricks
+        .iter()
+        .enumerate()
+        .map(|(index, _)| map.count_falling_bricks(index, &supported_by_map))
+        .sum()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 22;
+
+    type Input<'a> = Map;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day22>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
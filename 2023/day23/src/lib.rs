@@ -0,0 +1,310 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use aoc_grid::{Direction, Grid, Point};
+use std::collections::{HashMap, HashSet};
+
+#[derive(PartialEq)]
+enum Tile {
+    Path,
+    Rock,
+    Slope(Direction),
+}
+
+impl Tile {
+    fn parse(token: &str) -> Result<Self, ParseError> {
+        match token {
+            "." => Ok(Self::Path),
+            "#" => Ok(Self::Rock),
+            _ => token
+                .chars()
+                .next()
+                .and_then(Direction::from_arrow)
+                .map(Self::Slope)
+                .ok_or_else(|| ParseError::new(token, "'.', '#' or a slope")),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Path {
+    location: Point,
+    visited: HashSet<Point>,
+}
+
+pub struct Map {
+    tiles: Grid<Tile>,
+}
+
+/// The maze reduced to its junctions, with the length of the corridor
+/// between each pair of neighbouring junctions. The start and the finish
+/// count as junctions.
+struct Junctions {
+    edges: Vec<Vec<(usize, usize)>>,
+    /// The longest corridor into each junction.
+    longest_edges: Vec<usize>,
+    start: usize,
+    finish: usize,
+}
+
+/// State of the walk in [`Junctions::longest_path`].
+struct Walk {
+    /// Bitmask of the junctions already on the walk.
+    visited: u128,
+    length: usize,
+    /// The sum of the longest corridors into the junctions not yet visited.
+    /// The rest of the walk enters each of them at most once, so it can't be
+    /// longer than this.
+    unvisited_bound: usize,
+}
+
+impl Junctions {
+    /// Finds the longest walk to the finish that visits no junction twice,
+    /// skipping walks that can't get longer than the best one so far.
+    fn longest_path(&self, junction: usize, walk: Walk, longest: &mut usize) {
+        if junction == self.finish {
+            *longest = (*longest).max(walk.length);
+            return;
+        }
+
+        if walk.length + walk.unvisited_bound <= *longest {
+            return;
+        }
+
+        // The finish is at a dead end, so leaving the junction next to it any
+        // other way cuts it off.
+        let next_to_finish =
+            matches!(self.edges[self.finish][..], [(neighbour, _)] if neighbour == junction);
+
+        for &(next, corridor_length) in &self.edges[junction] {
+            if next_to_finish && next != self.finish {
+                continue;
+            }
+
+            if walk.visited & (1 << next) == 0 {
+                let next_walk = Walk {
+                    visited: walk.visited | (1 << next),
+                    length: walk.length + corridor_length,
+                    unvisited_bound: walk.unvisited_bound - self.longest_edges[next],
+                };
+
+                self.longest_path(next, next_walk, longest);
+            }
+        }
+    }
+}
+
+impl Map {
+    fn find_path_in_row(&self, y: usize) -> Point {
+        let x = self
+            .tiles
+            .row(y)
+            .iter()
+            .position(|tile| tile == &Tile::Path)
+            .unwrap();
+
+        Point::new(x as i32, y as i32)
+    }
+
+    fn find_start(&self) -> Point {
+        self.find_path_in_row(0)
+    }
+
+    fn find_finish(&self) -> Point {
+        self.find_path_in_row(self.tiles.height() - 1)
+    }
+
+    fn go(&self, point: &Point, direction: &Direction) -> Option<Point> {
+        let new_point = point.step(*direction);
+
+        let try_go = |point: Point| match self.tiles.get(point)? {
+            Tile::Rock => None,
+            Tile::Slope(d) => {
+                if d != &direction.opposite() {
+                    Some(point)
+                } else {
+                    None
+                }
+            }
+            _ => Some(point),
+        };
+
+        match &self.tiles[*point] {
+            Tile::Path => try_go(new_point),
+            Tile::Rock => None,
+            Tile::Slope(d) => {
+                if d == direction {
+                    try_go(new_point)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn open_neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.tiles
+            .neighbors4(point)
+            .filter(|&neighbour| self.tiles[neighbour] != Tile::Rock)
+    }
+
+    /// Compresses the maze with slopes walked like paths.
+    fn junctions(&self) -> Junctions {
+        let start = self.find_start();
+        let finish = self.find_finish();
+
+        let points: Vec<Point> = self
+            .tiles
+            .points()
+            .filter(|&point| self.tiles[point] != Tile::Rock)
+            .filter(|&point| {
+                point == start || point == finish || self.open_neighbours(point).count() > 2
+            })
+            .collect();
+        let indexes: HashMap<Point, usize> = points
+            .iter()
+            .enumerate()
+            .map(|(index, &point)| (point, index))
+            .collect();
+
+        let mut edges = vec![Vec::new(); points.len()];
+
+        for (index, &point) in points.iter().enumerate() {
+            for first_step in self.open_neighbours(point) {
+                let (mut previous, mut current, mut length) = (point, first_step, 1);
+
+                // Follow the corridor to the next junction, unless it's a dead end.
+                loop {
+                    if let Some(&junction) = indexes.get(&current) {
+                        edges[index].push((junction, length));
+                        break;
+                    }
+
+                    let Some(next) = self.open_neighbours(current).find(|&p| p != previous) else {
+                        break;
+                    };
+                    (previous, current, length) = (current, next, length + 1);
+                }
+            }
+        }
+
+        let longest_edges = edges
+            .iter()
+            .map(|edges| edges.iter().map(|&(_, length)| length).max().unwrap_or(0))
+            .collect();
+
+        Junctions {
+            edges,
+            longest_edges,
+            start: indexes[&start],
+            finish: indexes[&finish],
+        }
+    }
+
+    fn find_paths(&self) -> Vec<Path> {
+        let mut paths: Vec<Path> = Vec::new();
+        let destination = self.find_finish();
+
+        let mut queue = vec![Path {
+            visited: HashSet::new(),
+            location: self.find_start(),
+        }];
+
+        while let Some(path) = queue.pop() {
+            if path.location == destination {
+                paths.push(path);
+            } else {
+                Direction::ALL
+                    .iter()
+                    .filter_map(|direction| self.go(&path.location, direction))
+                    .filter(|new_point| !path.visited.contains(new_point))
+                    .for_each(|new_point| {
+                        let mut new_path = path.clone();
+                        new_path.visited.insert(new_path.location);
+                        new_path.location = new_point;
+                        queue.push(new_path);
+                    });
+            }
+        }
+
+        paths
+    }
+}
+
+fn parse_input(data: &str) -> Result<Map, ParseError> {
+    let tiles = Grid::try_parse(data, Tile::parse)?;
+
+    // The start and the finish are the only path tiles in the first and
+    // last rows.
+    for (y, line) in [
+        (0, data.lines().next()),
+        (tiles.height().saturating_sub(1), data.lines().last()),
+    ] {
+        if !tiles.row(y).contains(&Tile::Path) {
+            return Err(ParseError::new(line.unwrap_or(data), "a path tile"));
+        }
+    }
+
+    Ok(Map { tiles })
+}
+
+pub fn parse(data: &str) -> Result<Map, ParseError> {
+    parse_input(data)
+}
+
+pub fn part1(map: &Map) -> usize {
+    let paths = map.find_paths();
+
+    paths.iter().map(|path| path.visited.len()).max().unwrap()
+}
+
+/// `None` if the maze has more junctions than the search can track.
+pub fn part2(map: &Map) -> Option<usize> {
+    let junctions = map.junctions();
+
+    if junctions.edges.len() > u128::BITS as usize {
+        return None;
+    }
+
+    let walk = Walk {
+        visited: 1 << junctions.start,
+        length: 0,
+        unvisited_bound: junctions.longest_edges.iter().sum::<usize>()
+            - junctions.longest_edges[junctions.start],
+    };
+    let mut longest = 0;
+
+    junctions.longest_path(junctions.start, walk, &mut longest);
+
+    Some(longest)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 23;
+
+    type Input<'a> = Map;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day23>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,241 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Params, Solution};
+use aoc_math::solve_mod;
+
+/// A prime well above twice any coordinate, for solving the rock's
+/// equations modulo it.
+const PRIME: i128 = (1 << 61) - 1;
+
+#[derive(Debug)]
+struct Point {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Point {
+    fn from_xyz(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    fn coords(&self) -> [i128; 3] {
+        [self.x as i128, self.y as i128, self.z as i128]
+    }
+
+    fn parse(data: &str) -> Result<Self, ParseError> {
+        let [x, y, z] = parse::split_n::<3>(data, ",")?;
+
+        Ok(Point::from_xyz(
+            parse::number(x.trim())?,
+            parse::number(y.trim())?,
+            parse::number(z.trim())?,
+        ))
+    }
+}
+
+#[derive(Debug)]
+pub struct Hail {
+    location: Point,
+    velocity: Point,
+}
+
+impl Hail {
+    /// Where the paths of two hailstones cross in the future, ignoring the
+    /// z axis. The crossing is returned exactly as `[x, y, d]` for the point
+    /// `(x / d, y / d)` with `d` positive.
+    fn intersect(&self, other: &Self) -> Option<[i128; 3]> {
+        let [px, py, _] = self.location.coords();
+        let [vx, vy, _] = self.velocity.coords();
+        let [qx, qy, _] = other.location.coords();
+        let [ux, uy, _] = other.velocity.coords();
+
+        // Solve p + v * t = q + u * s by Cramer's rule.
+        let det = ux * vy - vx * uy;
+        let t = ux * (qy - py) - uy * (qx - px);
+        let s = vx * (qy - py) - vy * (qx - px);
+
+        let (det, t, s) = match det {
+            0 => return None,
+            det if det < 0 => (-det, -t, -s),
+            det => (det, t, s),
+        };
+
+        if t <= 0 || s <= 0 {
+            return None;
+        }
+
+        Some([px * det + vx * t, py * det + vy * t, det])
+    }
+
+    /// The three equations two hailstones give for the rock's position `P`
+    /// and velocity `V`, as rows of coefficients for `P` and `V` followed by
+    /// the constant. The rock hits hailstone `i` if `P - Pi` and `V - Vi`
+    /// are parallel, so `(P - Pi) × (V - Vi) = 0`. Subtracting that for two
+    /// hailstones cancels the only non-linear term, `P × V`, and leaves
+    /// `P × (Vi - Vj) + (Pi - Pj) × V = Pi × Vi - Pj × Vj`.
+    fn rock_equations(&self, other: &Self) -> [Vec<i128>; 3] {
+        let (pi, vi) = (self.location.coords(), self.velocity.coords());
+        let (pj, vj) = (other.location.coords(), other.velocity.coords());
+
+        let [dx, dy, dz] = [vi[0] - vj[0], vi[1] - vj[1], vi[2] - vj[2]];
+        let [ex, ey, ez] = [pi[0] - pj[0], pi[1] - pj[1], pi[2] - pj[2]];
+        let [cx, cy, cz] = [
+            cross(pi, vi)[0] - cross(pj, vj)[0],
+            cross(pi, vi)[1] - cross(pj, vj)[1],
+            cross(pi, vi)[2] - cross(pj, vj)[2],
+        ];
+
+        [
+            vec![0, dz, -dy, 0, -ez, ey, cx],
+            vec![-dz, 0, dx, ez, 0, -ex, cy],
+            vec![dy, -dx, 0, -ey, ex, 0, cz],
+        ]
+    }
+
+    /// Whether a rock thrown from `position` with `velocity` hits the
+    /// hailstone at a whole number of nanoseconds, now or later.
+    fn is_hit_by(&self, position: [i128; 3], velocity: [i128; 3]) -> bool {
+        let (location, hail_velocity) = (self.location.coords(), self.velocity.coords());
+
+        // Any axis where the two move at different speeds fixes the time.
+        let time = match (0..3).find(|&i| velocity[i] != hail_velocity[i]) {
+            Some(i) => {
+                let distance = position[i] - location[i];
+                let speed = hail_velocity[i] - velocity[i];
+
+                if distance % speed != 0 || distance / speed < 0 {
+                    return false;
+                }
+
+                distance / speed
+            }
+            None => 0,
+        };
+
+        (0..3).all(|i| position[i] + velocity[i] * time == location[i] + hail_velocity[i] * time)
+    }
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn is_in_area(min: i64, max: i64, [x, y, d]: [i128; 3]) -> bool {
+    let (min, max) = (min as i128 * d, max as i128 * d);
+
+    min <= x && x <= max && min <= y && y <= max
+}
+
+fn parse_input(data: &str) -> Result<Vec<Hail>, ParseError> {
+    data.lines()
+        .map(|line| {
+            let (location_str, velocity_str) = parse::split_once(line, " @ ")?;
+            let location = Point::parse(location_str)?;
+            let velocity = Point::parse(velocity_str)?;
+
+            Ok(Hail { location, velocity })
+        })
+        .collect()
+}
+
+pub fn parse(data: &str) -> Result<Vec<Hail>, ParseError> {
+    parse_input(data)
+}
+
+pub fn part1(hailstones: &[Hail], area_min: i64, area_max: i64) -> usize {
+    let mut counter = 0;
+
+    for (index, hail) in hailstones.iter().enumerate() {
+        for other_hail in hailstones.iter().skip(index + 1) {
+            if let Some(p) = hail.intersect(other_hail) {
+                if is_in_area(area_min, area_max, p) {
+                    counter += 1;
+                }
+            }
+        }
+    }
+
+    counter
+}
+
+/// Finds the position and velocity of the rock that hits every hailstone
+/// from the equations of the first few hailstones, and returns the sum of
+/// its position's coordinates. `None` if there's no such rock.
+pub fn part2(hailstones: &[Hail]) -> Option<i128> {
+    // Three hailstones are enough, unless their equations happen to be
+    // dependent. Then the next three might do.
+    hailstones.windows(3).take(10).find_map(|window| {
+        let rows: Vec<Vec<i128>> = window[0]
+            .rock_equations(&window[1])
+            .into_iter()
+            .chain(window[0].rock_equations(&window[2]))
+            .collect();
+
+        let solution: Vec<i128> = solve_mod(&rows, PRIME)?
+            .into_iter()
+            .map(|value| {
+                if value > PRIME / 2 {
+                    value - PRIME
+                } else {
+                    value
+                }
+            })
+            .collect();
+        let position = [solution[0], solution[1], solution[2]];
+        let velocity = [solution[3], solution[4], solution[5]];
+
+        hailstones
+            .iter()
+            .all(|hail| hail.is_hit_by(position, velocity))
+            .then(|| position.iter().sum())
+    })
+}
+
+pub struct Day24;
+
+pub struct Storm {
+    hailstones: Vec<Hail>,
+    area_min: i64,
+    area_max: i64,
+}
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 24;
+
+    type Input<'a> = Storm;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(data, &Params::default())
+    }
+
+    fn parse_with<'a>(data: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok(Storm {
+            hailstones: parse(data)?,
+            area_min: params.get("min", 200_000_000_000_000),
+            area_max: params.get("max", 400_000_000_000_000),
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input.hailstones, input.area_min, input.area_max).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input.hailstones).map_or(Answer::Unsolved, Answer::Int)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day24>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
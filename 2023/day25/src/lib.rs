@@ -0,0 +1,307 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+/// The wiring diagram: components by index, and every wire once, as a pair
+/// of component indexes.
+pub struct Graph<'a> {
+    names: Vec<&'a str>,
+    wires: Vec<(usize, usize)>,
+    /// For every component, its neighbours and the index of the wire to them.
+    neighbours: Vec<Vec<(usize, usize)>>,
+}
+
+/// A split of the components into two groups.
+pub struct Cut {
+    /// Whether each component is in the first group.
+    group: Vec<bool>,
+    /// How many wires connect the groups.
+    pub wires: usize,
+}
+
+impl Cut {
+    pub fn sizes(&self) -> (usize, usize) {
+        let first = self.group.iter().filter(|&&in_group| in_group).count();
+
+        (first, self.group.len() - first)
+    }
+}
+
+fn parse_component(name: &str) -> Result<&str, ParseError> {
+    if name.is_empty() {
+        return Err(ParseError::new(name, "a component name"));
+    }
+
+    parse::check_chars(name, |c| c.is_ascii_lowercase(), "a component name")
+}
+
+pub fn parse(data: &str) -> Result<Graph<'_>, ParseError> {
+    let mut graph = Graph {
+        names: Vec::new(),
+        wires: Vec::new(),
+        neighbours: Vec::new(),
+    };
+    let mut indexes: HashMap<&str, usize> = HashMap::new();
+
+    for line in data.lines() {
+        let (name, others) = parse::split_once(line, ": ")?;
+        let component = parse_component(name)?;
+        let from = graph.add_component(&mut indexes, component);
+
+        for other in others.split(' ') {
+            let other = parse_component(other)?;
+            if other == component {
+                return Err(ParseError::new(other, "a different component"));
+            }
+
+            let to = graph.add_component(&mut indexes, other);
+            graph.add_wire(from, to);
+        }
+    }
+
+    Ok(graph)
+}
+
+impl<'a> Graph<'a> {
+    fn add_component(&mut self, indexes: &mut HashMap<&'a str, usize>, name: &'a str) -> usize {
+        *indexes.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            self.neighbours.push(Vec::new());
+            self.names.len() - 1
+        })
+    }
+
+    fn add_wire(&mut self, from: usize, to: usize) {
+        let wire = self.wires.len();
+
+        self.wires.push((from, to));
+        self.neighbours[from].push((to, wire));
+        self.neighbours[to].push((from, wire));
+    }
+
+    /// Finds the components still reachable from `source` after pushing
+    /// unit flows along the wires, or `None` if `sink` is reached too.
+    /// Flow along a wire is positive from its first to its second component.
+    fn augment(&self, source: usize, sink: usize, flow: &mut [i8]) -> Option<Vec<bool>> {
+        let mut came_from: Vec<Option<(usize, usize)>> = vec![None; self.names.len()];
+        let mut reached = vec![false; self.names.len()];
+        let mut queue = VecDeque::from([source]);
+        reached[source] = true;
+
+        while let Some(component) = queue.pop_front() {
+            for &(next, wire) in &self.neighbours[component] {
+                let forward = self.wires[wire].0 == component;
+                let spare = if forward {
+                    flow[wire] < 1
+                } else {
+                    flow[wire] > -1
+                };
+
+                if spare && !reached[next] {
+                    reached[next] = true;
+                    came_from[next] = Some((component, wire));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if !reached[sink] {
+            return Some(reached);
+        }
+
+        let mut component = sink;
+        while let Some((previous, wire)) = came_from[component] {
+            flow[wire] += if self.wires[wire].0 == previous {
+                1
+            } else {
+                -1
+            };
+            component = previous;
+        }
+
+        None
+    }
+
+    /// Finds a cut of at most `wires` wires by max flow: with every wire
+    /// carrying one unit either way, the first component is separated from
+    /// another one as soon as no more flow fits between them. Each attempt
+    /// stops after `wires + 1` paths, so this is cheap when the cut is small.
+    pub fn cut_by_flow(&self, wires: usize) -> Option<Cut> {
+        for sink in 1..self.names.len() {
+            let mut flow = vec![0; self.wires.len()];
+
+            for paths in 0..=wires {
+                if let Some(group) = self.augment(0, sink, &mut flow) {
+                    return Some(Cut {
+                        group,
+                        wires: paths,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Finds a minimum cut with the Stoer–Wagner algorithm, or `None` if
+    /// there are fewer than two components.
+    ///
+    /// Each phase adds the components one at a time, always the one most
+    /// strongly connected to those already added. The wires to the last one
+    /// are a cut, and the last two are merged for the next phase; the
+    /// smallest of these cuts is a minimum cut.
+    pub fn stoer_wagner(&self) -> Option<Cut> {
+        let count = self.names.len();
+        if count < 2 {
+            return None;
+        }
+
+        let mut weights: Vec<HashMap<usize, usize>> = vec![HashMap::new(); count];
+        for &(from, to) in &self.wires {
+            *weights[from].entry(to).or_default() += 1;
+            *weights[to].entry(from).or_default() += 1;
+        }
+
+        let mut members: Vec<Vec<usize>> = (0..count).map(|component| vec![component]).collect();
+        let mut active: Vec<usize> = (0..count).collect();
+        let mut best: Option<(usize, Vec<usize>)> = None;
+
+        while active.len() > 1 {
+            let mut added = vec![false; count];
+            let mut keys = vec![0; count];
+            let mut heap: BinaryHeap<(usize, usize)> = BinaryHeap::new();
+            let (mut previous, mut last) = (active[0], active[0]);
+
+            for _ in 0..active.len() {
+                // A component that isn't connected to any added one is as
+                // good as any other.
+                let next = std::iter::from_fn(|| heap.pop())
+                    .find(|&(key, component)| !added[component] && key == keys[component])
+                    .or_else(|| {
+                        active
+                            .iter()
+                            .find(|&&component| !added[component])
+                            .map(|&component| (0, component))
+                    });
+                let Some((_, component)) = next else {
+                    break;
+                };
+
+                added[component] = true;
+                (previous, last) = (last, component);
+
+                for (&neighbour, &weight) in &weights[component] {
+                    if !added[neighbour] {
+                        keys[neighbour] += weight;
+                        heap.push((keys[neighbour], neighbour));
+                    }
+                }
+            }
+
+            if best.as_ref().is_none_or(|(wires, _)| keys[last] < *wires) {
+                best = Some((keys[last], members[last].clone()));
+            }
+
+            for (neighbour, weight) in std::mem::take(&mut weights[last]) {
+                weights[neighbour].remove(&last);
+
+                if neighbour != previous {
+                    *weights[previous].entry(neighbour).or_default() += weight;
+                    *weights[neighbour].entry(previous).or_default() += weight;
+                }
+            }
+
+            let merged = std::mem::take(&mut members[last]);
+            members[previous].extend(merged);
+            active.retain(|&component| component != last);
+        }
+
+        best.map(|(wires, members)| {
+            let mut group = vec![false; count];
+            members
+                .into_iter()
+                .for_each(|component| group[component] = true);
+
+            Cut { group, wires }
+        })
+    }
+
+    /// The diagram in Graphviz format, with the two groups of `cut` in
+    /// different colours and the wires between them in bold red.
+    pub fn to_dot(&self, cut: &Cut) -> String {
+        let mut lines = Vec::new();
+
+        lines.push(String::from("graph {"));
+
+        for (name, in_group) in self.names.iter().zip(&cut.group) {
+            let colour = if *in_group { "lightblue" } else { "lightgrey" };
+            lines.push(format!(
+                "  {} [ style = filled, fillcolor = {} ];",
+                name, colour
+            ));
+        }
+
+        lines.push(String::new());
+
+        for &(from, to) in &self.wires {
+            let style = if cut.group[from] != cut.group[to] {
+                " [ color = red, penwidth = 3 ]"
+            } else {
+                ""
+            };
+            lines.push(format!(
+                "  {} -- {}{};",
+                self.names[from], self.names[to], style
+            ));
+        }
+
+        lines.push(String::from("}"));
+
+        lines.join("\n")
+    }
+}
+
+/// `None` if three wires don't split the components in two.
+pub fn part1(graph: &Graph) -> Option<usize> {
+    graph
+        .cut_by_flow(3)
+        .map(|cut| cut.sizes())
+        .map(|(first, second)| first * second)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 25;
+
+    type Input<'a> = Graph<'a>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day25>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_stoer_wagner() {
+        let graph = parse(include_str!("../fixtures/example.txt")).unwrap();
+        let cut = graph.stoer_wagner().unwrap();
+
+        assert_eq!(3, cut.wires);
+        assert_eq!(54, cut.sizes().0 * cut.sizes().1);
+        assert_eq!(3, graph.to_dot(&cut).matches("red").count());
+    }
+}
@@ -0,0 +1,36 @@
+use aoc2023_day25::{parse, part1};
+use aoc_common::input;
+
+fn main() {
+    let data = input::load_or_exit(2023, 25);
+    let graph = input::parse_or_exit(&data, parse);
+
+    // `--dot` prints only the diagram, with the three wires to cut marked,
+    // so it can be piped straight into Graphviz.
+    if input::flag("dot") {
+        match graph.cut_by_flow(3) {
+            Some(cut) => println!("{}", graph.to_dot(&cut)),
+            None => eprintln!("No three wires split the components in two"),
+        }
+        return;
+    }
+
+    // `--check` finds a minimum cut with Stoer–Wagner as well.
+    if input::flag("check") {
+        match graph.stoer_wagner() {
+            Some(cut) => {
+                let (first, second) = cut.sizes();
+                println!(
+                    "Minimum cut: {} wires between {} and {} components",
+                    cut.wires, first, second
+                );
+            }
+            None => println!("Minimum cut: fewer than two components"),
+        }
+    }
+
+    match part1(&graph) {
+        Some(product) => println!("Day 25 Part 1: {}", product),
+        None => println!("Day 25 Part 1: no three wires split the components in two"),
+    }
+}
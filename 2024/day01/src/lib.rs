@@ -0,0 +1,77 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+pub fn parse(data: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let mut list1 = Vec::new();
+    let mut list2 = Vec::new();
+
+    for line in data.lines() {
+        let [a, b] = parse::words::<2>(line)?;
+
+        list1.push(parse::number::<i32>(a)?);
+        list2.push(parse::number::<i32>(b)?);
+    }
+
+    list1.sort();
+    list2.sort();
+
+    Ok((list1, list2))
+}
+
+pub fn part1((list1, list2): &(Vec<i32>, Vec<i32>)) -> i32 {
+    list1
+        .iter()
+        .zip(list2.iter())
+        .map(|(v1, v2)| (v1 - v2).abs())
+        .sum::<i32>()
+}
+
+pub fn part2((list1, list2): &(Vec<i32>, Vec<i32>)) -> i32 {
+    let mut number_counts: HashMap<i32, i32> = HashMap::new();
+
+    for &value in list2 {
+        let count = number_counts.get(&value).unwrap_or(&0) + 1;
+        number_counts.insert(value, count);
+    }
+
+    let mut sum = 0;
+
+    for value in list1 {
+        let count = number_counts.get(value).unwrap_or(&0);
+        sum += value * count;
+    }
+
+    sum
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Input<'a> = (Vec<i32>, Vec<i32>);
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day01>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
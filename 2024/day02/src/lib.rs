@@ -0,0 +1,89 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+fn is_report_safe(report: &[u32]) -> bool {
+    if report.is_sorted() || report.iter().rev().is_sorted() {
+        for numbers in report.windows(2) {
+            assert_eq!(numbers.len(), 2);
+
+            let diff = numbers[0].abs_diff(numbers[1]);
+            if diff == 0 || diff > 3 {
+                return false;
+            }
+        }
+
+        true
+    } else {
+        false
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    data.lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(parse::number::<u32>)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+pub fn part1(reports: &[Vec<u32>]) -> usize {
+    reports
+        .iter()
+        .filter(|report| is_report_safe(report))
+        .count()
+}
+
+pub fn part2(reports: &[Vec<u32>]) -> usize {
+    let mut safe_reports = 0;
+
+    for report in reports {
+        if is_report_safe(report) {
+            safe_reports += 1;
+        } else {
+            for i in 0..report.len() {
+                let mut tmp_report = report.clone();
+                tmp_report.remove(i);
+
+                if is_report_safe(&tmp_report) {
+                    safe_reports += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    safe_reports
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,164 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+pub enum Token {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+fn parse_mul(input: &str) -> Option<(u32, u32)> {
+    if !input.starts_with("mul(") {
+        return None;
+    }
+
+    let mut rest = input;
+    rest = &rest[4..];
+
+    let (number_a, end) = parse_number(rest)?;
+    rest = &rest[end..];
+
+    if !is_next_char(rest, ',') {
+        return None;
+    }
+
+    rest = &rest[1..];
+
+    let (number_b, end) = parse_number(rest)?;
+    rest = &rest[end..];
+
+    if !is_next_char(rest, ')') {
+        return None;
+    }
+
+    Some((number_a, number_b))
+}
+
+fn parse_number(input: &str) -> Option<(u32, usize)> {
+    let mut end = 0;
+
+    for char in input.chars().take(3) {
+        if char.is_ascii_digit() {
+            end += 1;
+        } else {
+            break;
+        }
+    }
+
+    if end > 0 {
+        let number = input[..end].parse::<u32>();
+
+        if let Ok(number) = number {
+            return Some((number, end));
+        }
+    }
+
+    None
+}
+
+fn is_next_char(input: &str, c: char) -> bool {
+    let char = input.chars().next();
+
+    char.is_some() && char.unwrap() == c
+}
+
+pub fn parse(data: &str) -> Result<Vec<Token>, ParseError> {
+    // Anything goes in corrupted memory, as long as it's one byte per
+    // character.
+    let mut rest = parse::check_chars(data, |c| c.is_ascii(), "an ASCII character")?;
+    let mut tokens: Vec<Token> = Vec::new();
+
+    loop {
+        if rest.is_empty() {
+            break;
+        }
+
+        match rest {
+            s if s.starts_with("mul(") => {
+                let number = parse_mul(rest);
+                rest = &rest[4..];
+
+                if let Some((a, b)) = number {
+                    tokens.push(Token::Mul(a, b));
+                }
+            }
+            s if s.starts_with("do()") => {
+                tokens.push(Token::Do);
+                rest = &rest[4..];
+            }
+            s if s.starts_with("don't()") => {
+                tokens.push(Token::Dont);
+                rest = &rest[7..];
+            }
+            _ => {
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+pub fn part1(tokens: &[Token]) -> u32 {
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::Mul(x, y) => x * y,
+            _ => 0,
+        })
+        .sum()
+}
+
+pub fn part2(tokens: &[Token]) -> u32 {
+    let mut sum = 0;
+    let mut enabled = true;
+
+    for token in tokens {
+        match token {
+            Token::Mul(x, y) => {
+                if enabled {
+                    sum += x * y;
+                }
+            }
+            Token::Do => {
+                enabled = true;
+            }
+            Token::Dont => {
+                enabled = false;
+            }
+        }
+    }
+
+    sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Token>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day03>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
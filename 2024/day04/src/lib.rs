@@ -0,0 +1,121 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+fn match_horizontal(input: &str, index: usize, word: &str) -> bool {
+    if word.len() + index > input.len() {
+        return false;
+    }
+
+    input[index..index + word.len()].eq(word)
+}
+
+fn match_vertical(input: &str, index: usize, width: usize, word: &str) -> bool {
+    let mut start = index;
+
+    for char in word.chars() {
+        if start + 1 > input.len() {
+            return false;
+        }
+
+        if input[start..start + 1] != char.to_string() {
+            return false;
+        }
+
+        start += width;
+    }
+
+    true
+}
+
+/// The search works on the raw text, so every line must be as long as the
+/// first one and end with a newline.
+pub fn parse(data: &str) -> Result<&str, ParseError> {
+    let width = data
+        .find('\n')
+        .ok_or_else(|| ParseError::after(data, "a line break"))?;
+
+    for line in data.lines() {
+        parse::check_chars(line, |c| c.is_ascii_uppercase(), "a letter")?;
+
+        if line.len() != width {
+            return Err(ParseError::new(line, format!("{} letters", width)));
+        }
+    }
+
+    Ok(data)
+}
+
+pub fn part1(data: &str) -> usize {
+    let width = data.find('\n').unwrap() + 1;
+    let mut count = 0;
+
+    for index in 0..data.len() {
+        let word = match &data[index..index + 1] {
+            "X" => "XMAS",
+            "S" => "SAMX",
+            _ => continue,
+        };
+
+        for step in [width, width + 1, width - 1] {
+            if match_vertical(data, index, step, word) {
+                count += 1;
+            }
+        }
+
+        if match_horizontal(data, index, word) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+pub fn part2(data: &str) -> usize {
+    let width = data.find('\n').unwrap() + 1;
+    let mut count = 0;
+
+    for index in 0..data.len() {
+        if &data[index..index + 1] == "A"
+            && index > width + 1
+            && (match_vertical(data, index - width - 1, width + 1, "MAS")
+                || match_vertical(data, index - width - 1, width + 1, "SAM"))
+            && (match_vertical(data, index - width + 1, width - 1, "MAS")
+                || match_vertical(data, index - width + 1, width - 1, "SAM"))
+        {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input<'a> = &'a str;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,162 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+struct Rule {
+    before: u32,
+    after: Vec<u32>,
+}
+
+impl PartialOrd for Rule {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.before == other.before {
+            return Some(Ordering::Equal);
+        }
+
+        if self.after.contains(&other.before) {
+            return Some(Ordering::Less);
+        }
+
+        if other.after.contains(&self.before) {
+            return Some(Ordering::Greater);
+        }
+
+        None
+    }
+}
+
+fn sum_middles(updates: &[Vec<u32>]) -> u32 {
+    updates
+        .iter()
+        .map(|update| update[update.len() / 2])
+        .sum::<u32>()
+}
+
+pub struct Input {
+    rule_book: HashMap<u32, Rule>,
+    updates: Vec<Vec<u32>>,
+}
+
+fn parse_update(line: &str) -> Result<Vec<u32>, ParseError> {
+    let update = line
+        .split(',')
+        .map(parse::number::<u32>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if update.len() % 2 == 0 {
+        return Err(ParseError::new(line, "an odd number of pages"));
+    }
+
+    Ok(update)
+}
+
+pub fn parse(data: &str) -> Result<Input, ParseError> {
+    let (rules, updates) = parse::split_once(data, "\n\n")?;
+
+    let single_rules = rules
+        .lines()
+        .map(|line| {
+            let (a, b) = parse::split_once(line, "|")?;
+
+            Ok((parse::number::<u32>(a)?, parse::number::<u32>(b)?))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    let mut rule_book: HashMap<u32, Rule> = HashMap::new();
+
+    for (before, after) in single_rules {
+        if let Some(rule) = rule_book.get_mut(&before) {
+            rule.after.push(after);
+        } else {
+            rule_book.insert(
+                before,
+                Rule {
+                    before,
+                    after: vec![after],
+                },
+            );
+        }
+
+        rule_book.entry(after).or_insert(Rule {
+            before: after,
+            after: Vec::new(),
+        });
+    }
+
+    let updates = updates
+        .lines()
+        .map(parse_update)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Input { rule_book, updates })
+}
+
+fn is_valid(input: &Input, update: &[u32]) -> bool {
+    update
+        .iter()
+        .map(|number| &input.rule_book[number])
+        .is_sorted()
+}
+
+pub fn part1(input: &Input) -> u32 {
+    let valid_updates = input
+        .updates
+        .iter()
+        .filter(|update| is_valid(input, update))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    sum_middles(&valid_updates)
+}
+
+pub fn part2(input: &Input) -> u32 {
+    let fixed_updates = input
+        .updates
+        .iter()
+        .filter(|update| !is_valid(input, update))
+        .map(|update| {
+            let mut rules = update
+                .iter()
+                .map(|number| &input.rule_book[number])
+                .collect::<Vec<_>>();
+            rules.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            rules.iter().map(|r| r.before).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    sum_middles(&fixed_updates)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Input;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day05>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
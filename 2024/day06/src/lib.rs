@@ -0,0 +1,170 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use aoc_grid::{Direction, Grid, Point};
+use std::collections::{HashMap, HashSet};
+
+#[derive(PartialEq, Copy, Clone)]
+enum Tile {
+    Empty,
+    Blocked,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum MapWalkResult {
+    LeftMap,
+    Looped,
+}
+
+type Map = Grid<Tile>;
+
+fn find_player(map: &Grid<char>) -> Option<(Point, Direction)> {
+    map.iter()
+        .find_map(|(point, &c)| Some((point, Direction::from_arrow(c)?)))
+}
+
+fn parse_tile(token: &str) -> Result<char, ParseError> {
+    match token.chars().next() {
+        Some(c @ ('.' | '#' | '^' | '>' | 'v' | '<')) => Ok(c),
+        _ => Err(ParseError::new(token, "'.', '#' or the guard")),
+    }
+}
+
+fn has_visited(
+    visited: &HashMap<Point, HashSet<Direction>>,
+    point: &Point,
+    direction: &Direction,
+) -> bool {
+    if let Some(directions) = visited.get(point) {
+        if directions.contains(direction) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn walk_map(
+    map: &mut Map,
+    start_position: Point,
+    start_direction: Direction,
+    find_possible_obstacles: bool,
+) -> (
+    MapWalkResult,
+    HashMap<Point, HashSet<Direction>>,
+    HashSet<Point>,
+) {
+    let mut visited: HashMap<Point, HashSet<Direction>> = HashMap::new();
+    let mut possible_obstacles: HashSet<Point> = HashSet::new();
+
+    let mut player_position = start_position;
+    let mut player_direction = start_direction;
+
+    loop {
+        if has_visited(&visited, &player_position, &player_direction) {
+            return (MapWalkResult::Looped, visited, possible_obstacles);
+        }
+
+        visited
+            .entry(player_position)
+            .or_default()
+            .insert(player_direction);
+
+        let new_position = player_position.step(player_direction);
+
+        if let Some(tile) = map.get(new_position) {
+            match tile {
+                Tile::Empty => {
+                    if find_possible_obstacles {
+                        map[new_position] = Tile::Blocked;
+                        let (result, _, _) = walk_map(map, start_position, start_direction, false);
+                        map[new_position] = Tile::Empty;
+
+                        if result == MapWalkResult::Looped {
+                            possible_obstacles.insert(new_position);
+                        }
+                    }
+
+                    player_position = new_position;
+                }
+                Tile::Blocked => {
+                    player_direction = player_direction.turn_right();
+                }
+            }
+        } else {
+            return (MapWalkResult::LeftMap, visited, possible_obstacles);
+        }
+    }
+}
+
+pub struct Lab {
+    map: Map,
+    player_position: Point,
+    player_direction: Direction,
+}
+
+pub fn parse(data: &str) -> Result<Lab, ParseError> {
+    let chars = Grid::try_parse(data, parse_tile)?;
+
+    let map = chars.map(|&c| match c {
+        '#' => Tile::Blocked,
+        _ => Tile::Empty,
+    });
+    let (player_position, player_direction) =
+        find_player(&chars).ok_or_else(|| ParseError::after(data, "the guard"))?;
+
+    Ok(Lab {
+        map,
+        player_position,
+        player_direction,
+    })
+}
+
+pub fn part1(lab: &Lab) -> usize {
+    let mut map = lab.map.clone();
+    let (result, visited, _) = walk_map(&mut map, lab.player_position, lab.player_direction, false);
+
+    assert_eq!(result, MapWalkResult::LeftMap);
+
+    visited.len()
+}
+
+pub fn part2(lab: &Lab) -> usize {
+    let mut map = lab.map.clone();
+    let (result, _, possible_obstacles) =
+        walk_map(&mut map, lab.player_position, lab.player_direction, true);
+
+    assert_eq!(result, MapWalkResult::LeftMap);
+
+    possible_obstacles.len()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Lab;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day06>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
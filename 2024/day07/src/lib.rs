@@ -0,0 +1,109 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Equation {
+    result: u64,
+    operants: Vec<u64>,
+}
+
+fn find_solutions(ops: &[u64], with_concat: bool) -> Vec<u64> {
+    match ops {
+        [tail @ .., head] => {
+            if tail.is_empty() {
+                return vec![*head];
+            }
+
+            let solutions = find_solutions(tail, with_concat);
+            let mut mul_soltions = solutions
+                .iter()
+                .map(|value| *head * value)
+                .collect::<Vec<u64>>();
+            let mut add_solutions = solutions
+                .iter()
+                .map(|value| *head + value)
+                .collect::<Vec<u64>>();
+
+            mul_soltions.append(&mut add_solutions);
+
+            if with_concat {
+                let mut concat_solutions = solutions
+                    .iter()
+                    .map(|value| {
+                        let decimal_places = (0..).take_while(|i| 10u64.pow(*i) <= *head).count();
+                        value * 10u64.pow(decimal_places as u32) + head
+                    })
+                    .collect::<Vec<u64>>();
+
+                mul_soltions.append(&mut concat_solutions);
+            }
+
+            mul_soltions
+        }
+        [] => unreachable!("No operants found"),
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Equation>, ParseError> {
+    data.lines()
+        .map(|line| {
+            let (result_str, operants_str) = parse::split_once(line, ": ")?;
+            let operants = operants_str
+                .split(' ')
+                .map(parse::number::<u64>)
+                .collect::<Result<_, _>>()?;
+
+            Ok(Equation {
+                result: parse::number(result_str)?,
+                operants,
+            })
+        })
+        .collect::<Result<Vec<Equation>, _>>()
+}
+
+fn sum_solvable(equations: &[Equation], with_concat: bool) -> u64 {
+    equations
+        .iter()
+        .filter(|eq| find_solutions(&eq.operants, with_concat).contains(&eq.result))
+        .map(|eq| eq.result)
+        .sum::<u64>()
+}
+
+pub fn part1(equations: &[Equation]) -> u64 {
+    sum_solvable(equations, false)
+}
+
+pub fn part2(equations: &[Equation]) -> u64 {
+    sum_solvable(equations, true)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<Equation>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day07>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,160 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
+struct Position {
+    x: i64,
+    y: i64,
+}
+
+impl Position {
+    fn sub(&self, other: &Self) -> Self {
+        Position {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+
+    fn add(&self, other: &Position) -> Self {
+        Position {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+
+    fn in_bounds(&self, width: i64, height: i64) -> bool {
+        self.x >= 0 && self.x < width && self.y >= 0 && self.y < height
+    }
+}
+
+pub struct Input {
+    antennas: BTreeMap<char, Vec<Position>>,
+    width: i64,
+    height: i64,
+}
+
+pub fn parse(data: &str) -> Result<Input, ParseError> {
+    let width = data
+        .find('\n')
+        .ok_or_else(|| ParseError::after(data, "a line break"))?;
+    let height = data.lines().count() as i64;
+
+    let mut antennas: BTreeMap<char, Vec<Position>> = BTreeMap::new();
+
+    for (y, line) in data.lines().enumerate() {
+        parse::check_chars(
+            line,
+            |c| c == '.' || c.is_ascii_alphanumeric(),
+            "'.' or an antenna",
+        )?;
+
+        if line.len() != width {
+            return Err(ParseError::new(line, format!("{} characters", width)));
+        }
+
+        for (x, ch) in line.chars().enumerate() {
+            match ch {
+                '0'..='9' | 'a'..='z' | 'A'..='Z' => {
+                    antennas.entry(ch).or_default().push(Position {
+                        x: x as i64,
+                        y: y as i64,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(Input {
+        antennas,
+        width: width as i64,
+        height,
+    })
+}
+
+pub fn part1(input: &Input) -> usize {
+    let mut antinodes: BTreeSet<Position> = BTreeSet::new();
+
+    for positions in input.antennas.values() {
+        for i in 0..positions.len() - 1 {
+            let pos_a = &positions[i];
+            for pos_b in &positions[i + 1..positions.len()] {
+                let distance = pos_b.sub(pos_a);
+                let possible_antinodes = [
+                    pos_a.add(&distance),
+                    pos_a.sub(&distance),
+                    pos_b.add(&distance),
+                    pos_b.sub(&distance),
+                ]
+                .into_iter()
+                .filter(|position| position != pos_a && position != pos_b)
+                .filter(|position| position.in_bounds(input.width, input.height));
+
+                antinodes.extend(possible_antinodes);
+            }
+        }
+    }
+
+    antinodes.len()
+}
+
+pub fn part2(input: &Input) -> usize {
+    let mut antinodes: BTreeSet<Position> = BTreeSet::new();
+
+    for positions in input.antennas.values() {
+        for i in 0..positions.len() - 1 {
+            let pos_a = &positions[i];
+            for pos_b in &positions[i + 1..positions.len()] {
+                let distance = pos_b.sub(pos_a);
+
+                let mut current_position = *pos_a;
+
+                while current_position.in_bounds(input.width, input.height) {
+                    antinodes.insert(current_position);
+                    current_position = current_position.add(&distance);
+                }
+
+                current_position = *pos_a;
+
+                while current_position.in_bounds(input.width, input.height) {
+                    antinodes.insert(current_position);
+                    current_position = current_position.sub(&distance);
+                }
+            }
+        }
+    }
+
+    antinodes.len()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Input;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day08>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,78 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use aoc_grid::{Grid, Point};
+use std::collections::HashSet;
+use std::hash::RandomState;
+
+type Map = Grid<u32>;
+
+fn walk(map: &Map, point: Point, nines: &mut Vec<Point>) {
+    let height = map[point];
+
+    if height == 9 {
+        nines.push(point);
+    } else {
+        for neighbor in map.neighbors4(point) {
+            if map[neighbor] == height + 1 {
+                walk(map, neighbor, nines)
+            }
+        }
+    }
+}
+
+pub fn parse(data: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+    let map = Grid::try_parse(data, parse::number::<u32>)?;
+
+    Ok(map
+        .iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(start_point, _)| {
+            let mut nines = Vec::new();
+            walk(&map, start_point, &mut nines);
+
+            nines
+        })
+        .collect::<Vec<_>>())
+}
+
+pub fn part1(trails: &[Vec<Point>]) -> usize {
+    trails
+        .iter()
+        .map(|nines| HashSet::<_, RandomState>::from_iter(nines.iter()).len())
+        .sum::<usize>()
+}
+
+pub fn part2(trails: &[Vec<Point>]) -> usize {
+    trails.iter().map(|nines| nines.len()).sum::<usize>()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Vec<Point>>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day10>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
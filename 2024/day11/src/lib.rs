@@ -0,0 +1,108 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::BTreeMap;
+
+enum BlinkResult {
+    One(u64),
+    Two(u64, u64),
+}
+
+fn calculate_stone_count_sum(
+    stones: &[u64],
+    stone_map: &mut BTreeMap<(u64, u64), u64>,
+    blinks: u64,
+) -> u64 {
+    stones
+        .iter()
+        .map(|stone| calculate_stone_count(*stone, stone_map, blinks))
+        .sum::<u64>()
+}
+
+fn calculate_stone_count(
+    stone: u64,
+    stone_map: &mut BTreeMap<(u64, u64), u64>,
+    blinks: u64,
+) -> u64 {
+    if blinks == 0 {
+        return 1;
+    }
+
+    if let Some(count) = stone_map.get(&(stone, blinks)) {
+        return *count;
+    }
+
+    let count = match blink(stone) {
+        BlinkResult::One(new_stone) => calculate_stone_count(new_stone, stone_map, blinks - 1),
+        BlinkResult::Two(new_stone_a, new_stone_b) => {
+            calculate_stone_count(new_stone_a, stone_map, blinks - 1)
+                + calculate_stone_count(new_stone_b, stone_map, blinks - 1)
+        }
+    };
+
+    stone_map.insert((stone, blinks), count);
+
+    count
+}
+
+fn blink(number: u64) -> BlinkResult {
+    match (number, number_of_digits(number)) {
+        (0, _) => BlinkResult::One(1),
+        (s, digits) if digits % 2 == 0 => {
+            let power = 10u64.pow(digits as u32 / 2);
+            let upper = s / power;
+            let lower = s - upper * power;
+
+            BlinkResult::Two(upper, lower)
+        }
+        (s, _) => BlinkResult::One(s * 2024),
+    }
+}
+
+fn number_of_digits(number: u64) -> u64 {
+    (0..).take_while(|i| 10u64.pow(*i) <= number).count() as u64
+}
+
+pub fn parse(data: &str) -> Result<Vec<u64>, ParseError> {
+    data.split_whitespace()
+        .map(parse::number::<u64>)
+        .collect::<Result<Vec<_>, _>>()
+}
+
+pub fn part1(stones: &[u64]) -> u64 {
+    calculate_stone_count_sum(stones, &mut BTreeMap::new(), 25)
+}
+
+pub fn part2(stones: &[u64]) -> u64 {
+    calculate_stone_count_sum(stones, &mut BTreeMap::new(), 75)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Vec<u64>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day11>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
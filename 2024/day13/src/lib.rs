@@ -0,0 +1,159 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    a_x: usize,
+    a_y: usize,
+
+    b_x: usize,
+    b_y: usize,
+
+    prize_x: usize,
+    prize_y: usize,
+}
+
+impl Machine {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut machine = Self {
+            a_x: 0,
+            a_y: 0,
+            b_x: 0,
+            b_y: 0,
+            prize_x: 0,
+            prize_y: 0,
+        };
+
+        for line in input.lines() {
+            match line {
+                line if line.starts_with("Button A") => {
+                    let (x, y) = parse_button(line)?;
+
+                    machine.a_x = x;
+                    machine.a_y = y;
+                }
+                line if line.starts_with("Button B") => {
+                    let (x, y) = parse_button(line)?;
+
+                    machine.b_x = x;
+                    machine.b_y = y;
+                }
+                line if line.starts_with("Prize") => {
+                    let (x, y) = parse_price(line)?;
+
+                    machine.prize_x = x;
+                    machine.prize_y = y;
+                }
+                _ => return Err(ParseError::new(line, "a button or the prize")),
+            }
+        }
+
+        Ok(machine)
+    }
+
+    fn solve(&self) -> Option<(usize, usize)> {
+        let b1 = self.b_x * self.a_y;
+        let b2 = self.b_y * self.a_x;
+
+        let p1 = self.prize_x * self.a_y;
+        let p2 = self.prize_y * self.a_x;
+
+        let (b, p) = if b1 > b2 && p1 > p2 {
+            Some((b1 - b2, p1 - p2))
+        } else if b1 < b2 && p1 < p2 {
+            Some((b2 - b1, p2 - p1))
+        } else {
+            None
+        }?;
+
+        let b = if p % b == 0 { Some(p / b) } else { None }?;
+
+        let sub_b = b * self.b_x;
+
+        if self.prize_x > sub_b {
+            let p2 = self.prize_x - sub_b;
+
+            if p2.is_multiple_of(self.a_x) {
+                return Some((p2 / self.a_x, b));
+            }
+        }
+
+        None
+    }
+}
+
+fn parse_button(input: &str) -> Result<(usize, usize), ParseError> {
+    let (_, data) = parse::split_once(input, ": ")?;
+    let (x_data, y_data) = parse::split_once(data, ", ")?;
+    let x = parse::strip_prefix(x_data, "X+")?;
+    let y = parse::strip_prefix(y_data, "Y+")?;
+
+    Ok((parse::number(x)?, parse::number(y)?))
+}
+
+fn parse_price(input: &str) -> Result<(usize, usize), ParseError> {
+    let (_, data) = parse::split_once(input, ": ")?;
+    let (x_data, y_data) = parse::split_once(data, ", ")?;
+    let x = parse::strip_prefix(x_data, "X=")?;
+    let y = parse::strip_prefix(y_data, "Y=")?;
+
+    Ok((parse::number(x)?, parse::number(y)?))
+}
+
+pub fn parse(data: &str) -> Result<Vec<Machine>, ParseError> {
+    data.split("\n\n")
+        .map(Machine::parse)
+        .collect::<Result<Vec<_>, _>>()
+}
+
+pub fn part1(machines: &[Machine]) -> usize {
+    machines
+        .iter()
+        .filter_map(|machine| machine.solve())
+        .map(|(a, b)| a * 3 + b)
+        .sum::<usize>()
+}
+
+pub fn part2(machines: &[Machine]) -> usize {
+    machines
+        .iter()
+        .cloned()
+        .filter_map(|mut machine| {
+            machine.prize_x += 10000000000000;
+            machine.prize_y += 10000000000000;
+            machine.solve()
+        })
+        .map(|(a, b)| a * 3 + b)
+        .sum::<usize>()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day13>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
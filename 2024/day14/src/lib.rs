@@ -0,0 +1,200 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Params, Solution};
+use aoc_math::{gcd, lcm};
+
+#[derive(Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let (x, y) = parse::split_once(s, ",")?;
+
+        Ok(Self {
+            x: parse::number(x)?,
+            y: parse::number(y)?,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct Robot {
+    pos: Point,
+    vel: Point,
+}
+
+impl Robot {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let (pos_data, vel_data) = parse::split_once(s, " ")?;
+        let pos_data = parse::strip_prefix(pos_data, "p=")?;
+        let vel_data = parse::strip_prefix(vel_data, "v=")?;
+
+        Ok(Self {
+            pos: Point::parse(pos_data)?,
+            vel: Point::parse(vel_data)?,
+        })
+    }
+
+    fn simulate(&mut self, map_width: i32, map_height: i32, ticks: i32) {
+        self.pos.x += self.vel.x * ticks;
+        self.pos.y += self.vel.y * ticks;
+
+        self.pos.x %= map_width;
+        self.pos.y %= map_height;
+
+        if self.pos.x < 0 {
+            self.pos.x += map_width;
+        }
+
+        if self.pos.y < 0 {
+            self.pos.y += map_height;
+        }
+    }
+
+    fn period(&self, map_width: i32, map_height: i32) -> i32 {
+        let x_period = map_width / gcd(self.vel.x, map_width);
+        let y_period = map_height / gcd(self.vel.y, map_height);
+
+        lcm(x_period, y_period)
+    }
+}
+
+fn safety_factor(mut robots: Vec<Robot>, map_width: i32, map_height: i32, ticks: i32) -> i32 {
+    for robot in robots.iter_mut() {
+        robot.simulate(map_width, map_height, ticks);
+    }
+
+    let map_center_x = (map_width - 1) / 2;
+    let map_center_y = (map_height - 1) / 2;
+
+    let mut tl = 0;
+    let mut tr = 0;
+    let mut bl = 0;
+    let mut br = 0;
+
+    for robot in robots.iter() {
+        match robot.pos {
+            Point { x, y } if x < map_center_x && y < map_center_y => tl += 1,
+            Point { x, y } if x > map_center_x && y < map_center_y => tr += 1,
+            Point { x, y } if x < map_center_x && y > map_center_y => bl += 1,
+            Point { x, y } if x > map_center_x && y > map_center_y => br += 1,
+            _ => {}
+        }
+    }
+
+    tl * tr * bl * br
+}
+
+fn find_longest_streak(mut robots: Vec<Robot>, map_width: i32, map_height: i32, ticks: i32) -> i32 {
+    let mut max_streak = 0;
+    let mut tick_of_max_streak = 0;
+
+    let mut map = vec![vec![false; map_width as usize]; map_height as usize];
+
+    for tick in 1..ticks {
+        for robot in robots.iter_mut() {
+            robot.simulate(map_width, map_height, 1);
+            map[robot.pos.y as usize][robot.pos.x as usize] = true;
+        }
+
+        let longest_streak = map
+            .iter()
+            .map(|row| {
+                row.iter().fold((0, 0), |(current, longest), x| {
+                    if *x {
+                        (current + 1, longest.max(current + 1))
+                    } else {
+                        (0, longest)
+                    }
+                })
+            })
+            .map(|(_, longest)| longest)
+            .max()
+            .unwrap();
+
+        if longest_streak > max_streak {
+            max_streak = longest_streak;
+            tick_of_max_streak = tick;
+        }
+
+        for row in map.iter_mut() {
+            row.fill(false);
+        }
+    }
+
+    tick_of_max_streak
+}
+
+pub fn parse(data: &str) -> Result<Vec<Robot>, ParseError> {
+    let robots = data
+        .lines()
+        .map(Robot::parse)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if robots.is_empty() {
+        return Err(ParseError::after(data, "a robot"));
+    }
+
+    Ok(robots)
+}
+
+pub fn part1(robots: &[Robot], map_width: i32, map_height: i32) -> i32 {
+    safety_factor(robots.to_vec(), map_width, map_height, 100)
+}
+
+pub fn part2(robots: &[Robot], map_width: i32, map_height: i32) -> i32 {
+    let max_ticks = robots
+        .iter()
+        .map(|robot| robot.period(map_width, map_height))
+        .reduce(lcm)
+        .expect("No robots found");
+
+    find_longest_streak(robots.to_vec(), map_width, map_height, max_ticks)
+}
+
+pub struct Day14;
+
+pub struct Bathroom {
+    robots: Vec<Robot>,
+    width: i32,
+    height: i32,
+}
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Bathroom;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(data, &Params::default())
+    }
+
+    fn parse_with<'a>(data: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok(Bathroom {
+            robots: parse(data)?,
+            width: params.get("width", 101) as i32,
+            height: params.get("height", 103) as i32,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input.robots, input.width, input.height).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input.robots, input.width, input.height).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day14>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,303 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone)]
+enum Tile {
+    Wall,
+    Box,
+    BoxL,
+    BoxR,
+    Empty,
+}
+
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Position {
+    fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Clone)]
+struct Map {
+    tiles: Vec<Vec<Tile>>,
+    width: usize,
+    height: usize,
+    robot: Position,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let width = input.lines().next().map_or(0, |line| line.len());
+        let mut tiles: Vec<Vec<Tile>> = Vec::new();
+        let mut robot = None;
+
+        for (y, line) in input.lines().enumerate() {
+            parse::check_chars(line, |c| "#O.@".contains(c), "'#', 'O', '.' or '@'")?;
+
+            if line.len() != width {
+                return Err(ParseError::new(line, format!("{} tiles", width)));
+            }
+
+            if let Some(x) = line.find('@') {
+                robot = Some(Position { x, y });
+            }
+
+            tiles.push(
+                line.chars()
+                    .map(|c| match c {
+                        '#' => Tile::Wall,
+                        'O' => Tile::Box,
+                        _ => Tile::Empty,
+                    })
+                    .collect(),
+            );
+        }
+
+        let height = tiles.len();
+        let robot = robot.ok_or_else(|| ParseError::after(input, "the robot '@'"))?;
+
+        Ok(Self {
+            tiles,
+            width,
+            height,
+            robot,
+        })
+    }
+
+    fn step(&self, pos: &Position, dir: &Direction, amount: usize) -> Option<Position> {
+        match dir {
+            Direction::North if pos.y >= amount => Some(Position::new(pos.x, pos.y - amount)),
+            Direction::East if pos.x < self.width - amount => {
+                Some(Position::new(pos.x + amount, pos.y))
+            }
+            Direction::South if pos.y < self.height - amount => {
+                Some(Position::new(pos.x, pos.y + amount))
+            }
+            Direction::West if pos.x >= amount => Some(Position::new(pos.x - amount, pos.y)),
+            _ => None,
+        }
+    }
+
+    fn double_width(&self) -> Self {
+        let tiles = self
+            .tiles
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .flat_map(|tile| match tile {
+                        Tile::Box => vec![Tile::BoxL, Tile::BoxR],
+                        t => vec![t.clone(), t.clone()],
+                    })
+                    .collect::<Vec<Tile>>()
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            tiles,
+            width: self.width * 2,
+            height: self.height,
+            robot: Position {
+                x: self.robot.x * 2,
+                y: self.robot.y,
+            },
+        }
+    }
+}
+
+fn run(mut map: Map, dirs: &[Direction]) -> usize {
+    let mut movable_boxes: Vec<(Position, Tile)> = Vec::new();
+    let mut pos_to_check: Vec<Position> = Vec::new();
+
+    for dir in dirs {
+        let mut blocked = false;
+
+        if let Some(pos) = map.step(&map.robot, dir, 1) {
+            pos_to_check.push(pos);
+        }
+
+        while let Some(pos) = pos_to_check.pop() {
+            match map.tiles[pos.y][pos.x] {
+                Tile::Empty => {}
+                Tile::Wall => {
+                    blocked = true;
+                    pos_to_check.clear();
+                }
+                Tile::Box => {
+                    let new_pos = map
+                        .step(&pos, dir, 1)
+                        .expect("Boxes can't be on the edge of the map");
+
+                    pos_to_check.push(new_pos);
+                    movable_boxes.push((pos, Tile::Box));
+                }
+                Tile::BoxL => match dir {
+                    Direction::North | Direction::South => {
+                        let new_pos = map
+                            .step(&pos, dir, 1)
+                            .expect("Boxes can't be on the edge of the map");
+                        let right_pos = map
+                            .step(&pos, &Direction::East, 1)
+                            .expect("BoxR must exist, if BoxL exists");
+                        let new_right_pos = map
+                            .step(&right_pos, dir, 1)
+                            .expect("Boxes can't be on the edge of the map");
+
+                        movable_boxes.push((pos, Tile::BoxL));
+                        movable_boxes.push((right_pos, Tile::BoxR));
+                        pos_to_check.push(new_pos);
+                        pos_to_check.push(new_right_pos);
+                    }
+                    Direction::East => {
+                        let new_pos = map
+                            .step(&pos, dir, 2)
+                            .expect("Boxes can't be on the edge of the map");
+
+                        pos_to_check.push(new_pos);
+                        movable_boxes
+                            .push((map.step(&pos, &Direction::East, 1).unwrap(), Tile::BoxR));
+                        movable_boxes.push((pos, Tile::BoxL));
+                    }
+                    Direction::West => {
+                        panic!("Cannot encounter BoxL when moving West")
+                    }
+                },
+                Tile::BoxR => match dir {
+                    Direction::North | Direction::South => {
+                        pos_to_check.push(map.step(&pos, &Direction::West, 1).unwrap());
+                    }
+                    Direction::West => {
+                        let new_pos = map
+                            .step(&pos, dir, 2)
+                            .expect("Boxes can't be on the edge of the map");
+
+                        pos_to_check.push(new_pos);
+                        movable_boxes
+                            .push((map.step(&pos, &Direction::West, 1).unwrap(), Tile::BoxL));
+                        movable_boxes.push((pos, Tile::BoxR));
+                    }
+                    Direction::East => {
+                        panic!("Cannot encounter BoxR when moving East")
+                    }
+                },
+            }
+        }
+
+        if !blocked {
+            movable_boxes.sort();
+            movable_boxes.dedup();
+
+            for (pos, _) in &movable_boxes {
+                map.tiles[pos.y][pos.x] = Tile::Empty;
+            }
+
+            for (pos, tile) in movable_boxes.drain(..) {
+                if let Some(new_pos) = map.step(&pos, dir, 1) {
+                    map.tiles[new_pos.y][new_pos.x] = tile;
+                }
+            }
+
+            if let Some(new_pos) = map.step(&map.robot, dir, 1) {
+                map.robot = new_pos;
+            }
+        } else {
+            movable_boxes.clear();
+        }
+    }
+
+    sum_gps(&map)
+}
+
+fn sum_gps(map: &Map) -> usize {
+    map.tiles
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter().enumerate().filter_map(move |(x, tile)| {
+                if matches!(tile, Tile::Box | Tile::BoxL) {
+                    Some(x + 100 * y)
+                } else {
+                    None
+                }
+            })
+        })
+        .sum::<usize>()
+}
+
+pub struct Warehouse {
+    map: Map,
+    dirs: Vec<Direction>,
+}
+
+pub fn parse(data: &str) -> Result<Warehouse, ParseError> {
+    let (map_input, dir_input) = parse::split_once(data, "\n\n")?;
+
+    let map = Map::parse(map_input)?;
+
+    for line in dir_input.lines() {
+        parse::check_chars(line, |c| "^>v<".contains(c), "'^', '>', 'v' or '<'")?;
+    }
+
+    let dirs = dir_input
+        .chars()
+        .filter_map(|c| match c {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Warehouse { map, dirs })
+}
+
+pub fn part1(warehouse: &Warehouse) -> usize {
+    run(warehouse.map.clone(), &warehouse.dirs)
+}
+
+pub fn part2(warehouse: &Warehouse) -> usize {
+    run(warehouse.map.double_width(), &warehouse.dirs)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Warehouse;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day15>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,141 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use aoc_grid::{Direction, Grid, Point};
+use aoc_search::{dijkstra, Search};
+use std::collections::HashSet;
+
+#[derive(PartialEq)]
+enum Tile {
+    Wall,
+    Empty,
+}
+
+pub struct Map {
+    tiles: Grid<Tile>,
+    start: Point,
+    end: Point,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let chars = Grid::try_parse(input, |token| match token {
+            "#" | "." | "S" | "E" => Ok(token),
+            _ => Err(ParseError::new(token, "'#', '.', 'S' or 'E'")),
+        })?;
+
+        let tiles = chars.map(|&c| match c {
+            "#" => Tile::Wall,
+            _ => Tile::Empty,
+        });
+
+        Ok(Self {
+            tiles,
+            start: chars
+                .position(|&c| c == "S")
+                .ok_or_else(|| ParseError::after(input, "a start tile 'S'"))?,
+            end: chars
+                .position(|&c| c == "E")
+                .ok_or_else(|| ParseError::after(input, "an end tile 'E'"))?,
+        })
+    }
+}
+
+/// A reindeer's position and the direction it is facing.
+type State = (Point, Direction);
+
+fn successors(map: &Map, &(position, direction): &State) -> Vec<(State, usize)> {
+    let mut next = vec![
+        ((position, direction.turn_left()), 1000),
+        ((position, direction.turn_right()), 1000),
+    ];
+
+    let ahead = position.step(direction);
+    if map.tiles.get(ahead) == Some(&Tile::Empty) {
+        next.push(((ahead, direction), 1));
+    }
+
+    next
+}
+
+fn find_best_paths(map: &Map) -> Search<State, usize> {
+    dijkstra(
+        [(map.start, Direction::East)],
+        |state| successors(map, state),
+        |&(position, _)| position == map.end,
+    )
+}
+
+pub fn parse(data: &str) -> Result<Map, ParseError> {
+    Map::parse(data)
+}
+
+pub fn part1(map: &Map) -> usize {
+    find_best_paths(map)
+        .cost()
+        .expect("No path to the end tile")
+}
+
+/// The tiles that are part of at least one of the cheapest paths.
+pub fn best_path_tiles(map: &Map) -> HashSet<Point> {
+    let search = find_best_paths(map);
+
+    search
+        .states_on_shortest_paths(search.goals())
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect()
+}
+
+/// The maze with the tiles in `marked` drawn as `O`.
+pub fn render(map: &Map, marked: &HashSet<Point>) -> String {
+    let mut image = String::new();
+
+    for (point, tile) in map.tiles.iter() {
+        image.push(match tile {
+            Tile::Wall => '#',
+            Tile::Empty if marked.contains(&point) => 'O',
+            Tile::Empty => '.',
+        });
+
+        if point.x as usize == map.tiles.width() - 1 {
+            image.push('\n');
+        }
+    }
+
+    image
+}
+
+pub fn part2(map: &Map) -> usize {
+    best_path_tiles(map).len()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Map;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day16>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
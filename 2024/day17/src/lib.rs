@@ -0,0 +1,259 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+enum Op {
+    Adv(u64),
+    Bxl(u64),
+    Bst(u64),
+    Jnz(u64),
+    Bxc,
+    Out(u64),
+    Bdv(u64),
+    Cdv(u64),
+}
+
+impl Op {
+    fn parse(op: &str, operand_str: &str) -> Result<Self, ParseError> {
+        let operand = parse_three_bit(operand_str)?;
+        let combo = || match operand {
+            7 => Err(ParseError::new(operand_str, "a combo operand from 0 to 6")),
+            operand => Ok(operand),
+        };
+
+        match parse_three_bit(op)? {
+            0 => Ok(Self::Adv(combo()?)),
+            1 => Ok(Self::Bxl(operand)),
+            2 => Ok(Self::Bst(combo()?)),
+            3 if operand % 2 == 0 => Ok(Self::Jnz(operand)),
+            3 => Err(ParseError::new(operand_str, "an even jump target")),
+            4 => Ok(Self::Bxc),
+            5 => Ok(Self::Out(combo()?)),
+            6 => Ok(Self::Bdv(combo()?)),
+            _ => Ok(Self::Cdv(combo()?)),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct State {
+    a: u64,
+    b: u64,
+    c: u64,
+    pc: usize,
+    out: Vec<u64>,
+}
+
+impl State {
+    fn reset(&mut self) {
+        self.a = 0;
+        self.b = 0;
+        self.c = 0;
+        self.pc = 0;
+        self.out.clear();
+    }
+}
+
+fn parse_three_bit(input: &str) -> Result<u64, ParseError> {
+    match parse::number(input)? {
+        n @ 0..=7 => Ok(n),
+        _ => Err(ParseError::new(input, "a number from 0 to 7")),
+    }
+}
+
+fn parse_register(input: &str) -> Result<State, ParseError> {
+    let mut register = State {
+        a: 0,
+        b: 0,
+        c: 0,
+        pc: 0,
+        out: Vec::new(),
+    };
+
+    for line in input.lines() {
+        let (label, value) = parse::split_once(line, ": ")?;
+        match label {
+            "Register A" => register.a = parse::number(value)?,
+            "Register B" => register.b = parse::number(value)?,
+            "Register C" => register.c = parse::number(value)?,
+            _ => return Err(ParseError::new(label, "'Register A', 'B' or 'C'")),
+        }
+    }
+
+    Ok(register)
+}
+
+fn parse_program(input: &str) -> Result<(Vec<Op>, Vec<u64>), ParseError> {
+    let program = parse::strip_prefix(input, "Program: ")?;
+    let tokens = program.split(",").collect::<Vec<&str>>();
+
+    if tokens.len() % 2 != 0 {
+        return Err(ParseError::after(program, "an operand"));
+    }
+
+    let raw_program = tokens
+        .iter()
+        .map(|n| parse_three_bit(n))
+        .collect::<Result<Vec<u64>, _>>()?;
+
+    let program = tokens
+        .chunks(2)
+        .map(|op| Op::parse(op[0], op[1]))
+        .collect::<Result<_, _>>()?;
+
+    Ok((program, raw_program))
+}
+
+fn execute_program(program: &[Op], register: &mut State) {
+    while register.pc < program.len() {
+        let mut inc_pc = true;
+        match program[register.pc] {
+            Op::Adv(operand) => register.a = calculate_dv(register.a, operand, register),
+            Op::Bdv(operand) => register.b = calculate_dv(register.a, operand, register),
+            Op::Cdv(operand) => register.c = calculate_dv(register.a, operand, register),
+            Op::Bxl(operand) => register.b ^= operand,
+            Op::Bxc => register.b ^= register.c,
+            Op::Bst(operand) => register.b = combo_operand(operand as u8, register) % 8,
+            Op::Jnz(operand) => {
+                if register.a != 0 {
+                    assert_eq!(operand % 2, 0);
+                    register.pc = operand as usize / 2;
+
+                    inc_pc = false
+                }
+            }
+            Op::Out(operand) => {
+                register
+                    .out
+                    .push(combo_operand(operand as u8, register) % 8);
+            }
+        }
+
+        if inc_pc {
+            register.pc += 1;
+        }
+    }
+}
+
+fn find_a(
+    register: &mut State,
+    program: &[Op],
+    raw_program: &[u64],
+    base: u64,
+    pos: usize,
+) -> Option<u64> {
+    let mut mul = 1;
+    for _ in 0..pos {
+        mul *= 8;
+    }
+
+    for i in 0..8 {
+        let a = mul * i + base;
+
+        register.reset();
+        register.a = a;
+        execute_program(program, register);
+
+        if register.out.len() > pos && raw_program[pos] == register.out[pos] {
+            if pos == 0 {
+                return Some(a);
+            }
+            if let Some(a) = find_a(register, program, raw_program, a, pos - 1) {
+                return Some(a);
+            }
+        }
+    }
+
+    None
+}
+
+#[inline]
+fn calculate_dv(value: u64, operand: u64, register: &State) -> u64 {
+    (value as f64 / 2.0f64.powi(combo_operand(operand as u8, register) as i32)).trunc() as u64
+}
+
+#[inline]
+fn combo_operand(operand: u8, register: &State) -> u64 {
+    match operand {
+        0..=3 => operand as u64,
+        4 => register.a,
+        5 => register.b,
+        6 => register.c,
+        _ => unreachable!("Unknown combo operand {}", operand),
+    }
+}
+
+pub struct Computer {
+    register: State,
+    program: Vec<Op>,
+    raw_program: Vec<u64>,
+}
+
+pub fn parse(data: &str) -> Result<Computer, ParseError> {
+    let (register_input, program_input) = parse::split_once(data, "\n\n")?;
+
+    let register = parse_register(register_input.trim())?;
+    let (program, raw_program) = parse_program(program_input.trim())?;
+
+    Ok(Computer {
+        register,
+        program,
+        raw_program,
+    })
+}
+
+pub fn part1(computer: &Computer) -> String {
+    let mut register = computer.register.clone();
+
+    execute_program(&computer.program, &mut register);
+
+    register
+        .out
+        .iter()
+        .map(|&a| a.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+pub fn part2(computer: &Computer) -> u64 {
+    let mut register = computer.register.clone();
+
+    find_a(
+        &mut register,
+        &computer.program,
+        &computer.raw_program,
+        0,
+        computer.raw_program.len() - 1,
+    )
+    .unwrap()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 17;
+
+    type Input<'a> = Computer;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day17>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
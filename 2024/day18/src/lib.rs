@@ -0,0 +1,154 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Params, Solution};
+use aoc_grid::{Grid, Point};
+use aoc_search::astar;
+use std::cmp::Ordering;
+
+#[derive(Clone, PartialEq)]
+enum Tile {
+    Empty,
+    Blocked,
+}
+
+type Map = Grid<Tile>;
+
+fn set_blocks(map: &mut Map, blocks: &[Point]) {
+    map.fill(Tile::Empty);
+
+    for &block in blocks {
+        map[block] = Tile::Blocked;
+    }
+}
+
+fn shortest_path_length(map: &mut Map, blocks: &[Point]) -> Option<usize> {
+    set_blocks(map, blocks);
+    let goal = Point::new(map.width() as i32 - 1, map.height() as i32 - 1);
+
+    let search = astar(
+        [Point::new(0, 0)],
+        |&position| {
+            map.neighbors4(position)
+                .filter(|&neighbor| map[neighbor] == Tile::Empty)
+                .map(|neighbor| (neighbor, 1))
+        },
+        |&position| position.manhattan_distance(goal) as usize,
+        |&position| position == goal,
+    );
+
+    search.cost()
+}
+
+fn parse_block(line: &str, map_size: usize) -> Result<Point, ParseError> {
+    let (x, y) = parse::split_once(line, ",")?;
+    let block = Point::new(parse::number(x)?, parse::number(y)?);
+
+    let in_bounds = |n: i32| (0..map_size as i32).contains(&n);
+    if !in_bounds(block.x) || !in_bounds(block.y) {
+        return Err(ParseError::new(
+            line,
+            format!("a block inside {0}x{0}", map_size),
+        ));
+    }
+
+    Ok(block)
+}
+
+fn parse_input(input: &str, map_size: usize) -> Result<Vec<Point>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_block(line, map_size))
+        .collect()
+}
+
+pub const MAP_SIZE: usize = 71;
+pub const NUMBER_OF_BLOCKS: usize = 1024;
+
+/// Parses the falling blocks, of which part one needs the first
+/// `number_of_blocks`.
+pub fn parse(
+    data: &str,
+    map_size: usize,
+    number_of_blocks: usize,
+) -> Result<Vec<Point>, ParseError> {
+    let blocks = parse_input(data, map_size)?;
+
+    if blocks.len() < number_of_blocks {
+        return Err(ParseError::after(
+            data,
+            format!("{} blocks", number_of_blocks),
+        ));
+    }
+
+    Ok(blocks)
+}
+
+pub fn part1(blocks: &[Point], map_size: usize, number_of_blocks: usize) -> usize {
+    let mut map = Grid::new(map_size, map_size, Tile::Empty);
+
+    shortest_path_length(&mut map, &blocks[0..number_of_blocks]).unwrap()
+}
+
+pub fn part2(blocks: &[Point], map_size: usize) -> String {
+    let mut map = Grid::new(map_size, map_size, Tile::Empty);
+
+    let result = blocks.binary_search_by(|position| {
+        let index = blocks.iter().position(|p| p == position).unwrap();
+
+        if shortest_path_length(&mut map, &blocks[0..=index]).is_some() {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    });
+
+    let index = result.unwrap_or_else(|i| i);
+    format!("{},{}", blocks[index].x, blocks[index].y)
+}
+
+pub struct Day18;
+
+pub struct Memory {
+    blocks: Vec<Point>,
+    map_size: usize,
+    number_of_blocks: usize,
+}
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+
+    type Input<'a> = Memory;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(data, &Params::default())
+    }
+
+    fn parse_with<'a>(data: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        let map_size = params.get("size", MAP_SIZE as i64) as usize;
+        let number_of_blocks = params.get("blocks", NUMBER_OF_BLOCKS as i64) as usize;
+
+        Ok(Memory {
+            blocks: parse(data, map_size, number_of_blocks)?,
+            map_size,
+            number_of_blocks,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input.blocks, input.map_size, input.number_of_blocks).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input.blocks, input.map_size).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day18>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
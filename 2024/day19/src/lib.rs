@@ -0,0 +1,120 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+fn is_design_valid(design: &str, towels: &[&str]) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+
+    for towel in towels {
+        if let Some(sub_design) = design.strip_prefix(towel) {
+            if is_design_valid(sub_design, towels) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn count_possible_arrangements<'a>(
+    design: &'a str,
+    towels: &[&str],
+    cache: &mut HashMap<&'a str, usize>,
+) -> usize {
+    if design.is_empty() {
+        return 1;
+    }
+
+    let mut count = 0;
+
+    for towel in towels {
+        if let Some(sub_design) = design.strip_prefix(towel) {
+            if let Some(c) = cache.get(sub_design) {
+                count += c;
+            } else {
+                let c = count_possible_arrangements(sub_design, towels, cache);
+                cache.insert(sub_design, c);
+                count += c;
+            }
+        }
+    }
+
+    count
+}
+
+fn check_stripes(stripes: &str) -> Result<&str, ParseError> {
+    if stripes.is_empty() {
+        return Err(ParseError::new(stripes, "a colored stripe"));
+    }
+
+    parse::check_chars(
+        stripes,
+        |c| "wubrg".contains(c),
+        "'w', 'u', 'b', 'r' or 'g'",
+    )
+}
+
+pub fn parse(data: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    let (towels_input, designs_input) = parse::split_once(data, "\n\n")?;
+
+    let towels = towels_input
+        .split(", ")
+        .map(check_stripes)
+        .collect::<Result<Vec<&str>, _>>()?;
+    let designs = designs_input
+        .lines()
+        .map(check_stripes)
+        .collect::<Result<Vec<&str>, _>>()?;
+
+    Ok((towels, designs))
+}
+
+pub fn part1((towels, designs): &(Vec<&str>, Vec<&str>)) -> usize {
+    designs
+        .iter()
+        .filter(|d| is_design_valid(d, towels))
+        .count()
+}
+
+pub fn part2((towels, designs): &(Vec<&str>, Vec<&str>)) -> usize {
+    let mut cache: HashMap<&str, usize> = HashMap::new();
+
+    designs
+        .iter()
+        .filter(|d| is_design_valid(d, towels))
+        .map(|d| count_possible_arrangements(d, towels, &mut cache))
+        .sum::<usize>()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+
+    type Input<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day19>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
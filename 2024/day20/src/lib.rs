@@ -0,0 +1,208 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Params, Solution};
+use aoc_grid::{Grid, Point};
+use std::collections::HashMap;
+
+#[derive(PartialEq, Debug)]
+enum Tile {
+    Wall,
+    Trail,
+}
+
+struct Map {
+    tiles: Grid<Tile>,
+    start: Point,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let chars = Grid::try_parse(input, |token| match token {
+            "#" | "." | "S" | "E" => Ok(token),
+            _ => Err(ParseError::new(token, "'#', '.', 'S' or 'E'")),
+        })?;
+        let start = chars
+            .position(|&c| c == "S")
+            .ok_or_else(|| ParseError::after(input, "a start tile 'S'"))?;
+
+        let tiles = chars.map(|&c| match c {
+            "#" => Tile::Wall,
+            _ => Tile::Trail,
+        });
+
+        Ok(Self { tiles, start })
+    }
+
+    fn find_trail_with_deltas(&self, start: &Point, deltas: &[Point]) -> Vec<(Point, usize)> {
+        deltas
+            .iter()
+            .filter_map(|&delta| {
+                let target = *start + delta;
+
+                if self.tiles.get(target) == Some(&Tile::Trail) {
+                    Some((target, start.manhattan_distance(target) as usize))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+fn walk_map(map: &Map, start: Point) -> HashMap<Point, usize> {
+    let mut costs = HashMap::new();
+
+    let mut next_pos = Some(start);
+
+    let mut current_cost = 0;
+    while let Some(pos) = next_pos {
+        costs.insert(pos, current_cost);
+        current_cost += 1;
+
+        let neighbors = map
+            .tiles
+            .neighbors4(pos)
+            .filter(|neighbor| map.tiles[*neighbor] == Tile::Trail && !costs.contains_key(neighbor))
+            .collect::<Vec<Point>>();
+
+        assert!(neighbors.len() < 2);
+
+        next_pos = neighbors.first().cloned();
+    }
+
+    costs
+}
+
+fn find_deltas_for_distance(distance: i32) -> Vec<Point> {
+    let mut positions = Vec::new();
+
+    let diff = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+    for d in 2..=distance {
+        for (x, y) in (0..=d).zip((0..=d).rev()) {
+            for (diff_x, diff_y) in diff {
+                positions.push(Point::new(x * diff_x, y * diff_y));
+            }
+        }
+    }
+
+    positions.sort();
+    positions.dedup();
+
+    positions
+}
+
+fn find_shortcuts(
+    map: &Map,
+    costs: &HashMap<Point, usize>,
+    pos: &Point,
+    deltas: &[Point],
+) -> Vec<usize> {
+    let start_costs: usize = *costs.get(pos).unwrap();
+
+    map.find_trail_with_deltas(pos, deltas)
+        .iter()
+        .filter_map(|(end_pos, delta_cost)| {
+            let end_cost = costs.get(end_pos)?;
+
+            if *end_cost > start_costs + delta_cost {
+                Some(*end_cost - start_costs - delta_cost)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<usize>>()
+}
+
+fn count_shortcuts(
+    map: &Map,
+    costs: &HashMap<Point, usize>,
+    distance: i32,
+    min_savings: usize,
+) -> usize {
+    let deltas = find_deltas_for_distance(distance);
+
+    let shortcuts = costs
+        .keys()
+        .flat_map(|pos| find_shortcuts(map, costs, pos, &deltas))
+        .collect::<Vec<usize>>();
+
+    let mut grouped_shortcuts: HashMap<usize, usize> = HashMap::new();
+    for savings in shortcuts {
+        let entry = grouped_shortcuts.entry(savings).or_default();
+        *entry += 1;
+    }
+
+    grouped_shortcuts
+        .iter()
+        .filter_map(|(savings, count)| {
+            if *savings >= min_savings {
+                Some(*count)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub struct Race {
+    map: Map,
+    costs: HashMap<Point, usize>,
+}
+
+pub fn parse(data: &str) -> Result<Race, ParseError> {
+    let map = Map::parse(data)?;
+    let costs = walk_map(&map, map.start);
+
+    Ok(Race { map, costs })
+}
+
+pub fn part1(race: &Race, min_savings: usize) -> usize {
+    count_shortcuts(&race.map, &race.costs, 2, min_savings)
+}
+
+pub fn part2(race: &Race, min_savings: usize) -> usize {
+    count_shortcuts(&race.map, &race.costs, 20, min_savings)
+}
+
+pub struct Day20;
+
+pub struct Cheats {
+    race: Race,
+    min_savings: usize,
+}
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 20;
+
+    type Input<'a> = Cheats;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(data, &Params::default())
+    }
+
+    fn parse_with<'a>(data: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok(Cheats {
+            race: parse(data)?,
+            min_savings: params.get("min_savings", 100) as usize,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input.race, input.min_savings).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input.race, input.min_savings).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day20>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
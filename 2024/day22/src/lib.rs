@@ -0,0 +1,109 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+type Seq = (isize, isize, isize, isize);
+
+fn nth_secret(secret: usize, n: usize) -> usize {
+    let mut secret = secret;
+    for _ in 0..n {
+        secret = next_secret(secret);
+    }
+
+    secret
+}
+
+fn next_secret(secret: usize) -> usize {
+    let secret = (secret ^ (secret * 64)) % 16777216;
+    let secret = (secret ^ (secret / 32)) % 16777216;
+    (secret ^ (secret * 2048)) % 16777216
+}
+
+const NUMBER_OF_SECRETS: usize = 2000;
+
+pub fn parse(data: &str) -> Result<Vec<usize>, ParseError> {
+    let secrets = data
+        .lines()
+        .map(parse::number)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if secrets.is_empty() {
+        return Err(ParseError::after(data, "a secret number"));
+    }
+
+    Ok(secrets)
+}
+
+pub fn part1(init_secrets: &[usize]) -> usize {
+    init_secrets
+        .iter()
+        .map(|&s| nth_secret(s, NUMBER_OF_SECRETS))
+        .sum::<usize>()
+}
+
+pub fn part2(init_secrets: &[usize]) -> usize {
+    let mut scores: HashMap<Seq, usize> = HashMap::new();
+    let mut secrets: Vec<usize> = Vec::with_capacity(NUMBER_OF_SECRETS + 1);
+    let mut changes: Vec<isize> = Vec::with_capacity(NUMBER_OF_SECRETS);
+    let mut found_seq: Vec<Seq> = Vec::with_capacity(NUMBER_OF_SECRETS / 4);
+
+    for &secret in init_secrets {
+        secrets.clear();
+        changes.clear();
+        found_seq.clear();
+
+        secrets.push(secret % 10);
+        let mut secret = secret;
+        for _ in 0..NUMBER_OF_SECRETS {
+            secret = next_secret(secret);
+            secrets.push(secret % 10);
+        }
+
+        for w in secrets.windows(2) {
+            changes.push(w[1] as isize - w[0] as isize);
+        }
+
+        for (index, w) in changes.windows(4).enumerate() {
+            let seq = (w[0], w[1], w[2], w[3]);
+
+            if !found_seq.contains(&seq) {
+                let entry = scores.entry(seq).or_default();
+                *entry += secrets[index + 4];
+                found_seq.push(seq);
+            }
+        }
+    }
+
+    *scores.values().max().unwrap()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 22;
+
+    type Input<'a> = Vec<usize>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day22>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,409 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+#[derive(Clone)]
+enum Op {
+    And(String, String),
+    Xor(String, String),
+    Or(String, String),
+}
+
+impl Op {
+    fn inputs(&self) -> (&str, &str) {
+        match self {
+            Op::And(lhs, rhs) | Op::Xor(lhs, rhs) | Op::Or(lhs, rhs) => (lhs, rhs),
+        }
+    }
+}
+
+fn resolve(name: &str, values: &mut HashMap<String, u8>, ops: &HashMap<String, Op>) -> u8 {
+    if values.contains_key(name) {
+        return values[name];
+    }
+
+    match ops.get(name).unwrap() {
+        Op::And(lhs, rhs) => {
+            let l_value = resolve(lhs, values, ops);
+            let r_value = resolve(rhs, values, ops);
+            let value = l_value & r_value;
+            values.insert(name.to_string(), value);
+            value
+        }
+        Op::Xor(lhs, rhs) => {
+            let l_value = resolve(lhs, values, ops);
+            let r_value = resolve(rhs, values, ops);
+            let value = l_value ^ r_value;
+            values.insert(name.to_string(), value);
+            value
+        }
+        Op::Or(lhs, rhs) => {
+            let l_value = resolve(lhs, values, ops);
+            let r_value = resolve(rhs, values, ops);
+            let value = l_value | r_value;
+            values.insert(name.to_string(), value);
+            value
+        }
+    }
+}
+
+pub struct Device {
+    values: HashMap<String, u8>,
+    ops: HashMap<String, Op>,
+}
+
+fn parse_value(line: &str) -> Result<(String, u8), ParseError> {
+    let (name, value) = parse::split_once(line, ": ")?;
+
+    match value {
+        "0" | "1" => Ok((name.to_string(), parse::number(value)?)),
+        _ => Err(ParseError::new(value, "0 or 1")),
+    }
+}
+
+fn parse_gate(line: &str) -> Result<(String, Op), ParseError> {
+    let [lhs, op, rhs, arrow, output] = parse::split_n::<5>(line, " ")?;
+
+    if arrow != "->" {
+        return Err(ParseError::new(arrow, "'->'"));
+    }
+
+    if let Some(bit) = output.strip_prefix('z') {
+        parse::number::<usize>(bit)?;
+    }
+
+    let (lhs, rhs) = (lhs.to_string(), rhs.to_string());
+    let op = match op {
+        "AND" => Op::And(lhs, rhs),
+        "XOR" => Op::Xor(lhs, rhs),
+        "OR" => Op::Or(lhs, rhs),
+        _ => return Err(ParseError::new(op, "'AND', 'XOR' or 'OR'")),
+    };
+
+    Ok((output.to_string(), op))
+}
+
+pub fn parse(data: &str) -> Result<Device, ParseError> {
+    let (value_lines, ops_lines) = parse::split_once(data, "\n\n")?;
+
+    let values = value_lines
+        .lines()
+        .map(parse_value)
+        .collect::<Result<HashMap<String, u8>, _>>()?;
+
+    let ops = ops_lines
+        .lines()
+        .map(parse_gate)
+        .collect::<Result<HashMap<String, Op>, _>>()?;
+
+    for line in ops_lines.lines() {
+        let mut inputs = line.split(' ').step_by(2).take(2);
+
+        if let Some(wire) =
+            inputs.find(|&wire| !values.contains_key(wire) && !ops.contains_key(wire))
+        {
+            return Err(ParseError::new(wire, "a wire with a value or a gate"));
+        }
+    }
+
+    Ok(Device { values, ops })
+}
+
+pub fn part1(device: &Device) -> usize {
+    let mut values = device.values.clone();
+
+    device
+        .ops
+        .keys()
+        .filter_map(|name| {
+            if !name.starts_with("z") {
+                return None;
+            }
+
+            let value = resolve(name, &mut values, &device.ops);
+
+            let shift = name.trim_start_matches('z').parse::<usize>().unwrap();
+
+            Some((value as usize) << shift)
+        })
+        .sum::<usize>()
+}
+
+/// Like [`resolve`], but gives up on a loop of gates, which swapped
+/// outputs can create.
+fn evaluate<'a>(
+    name: &str,
+    values: &mut HashMap<&'a str, u8>,
+    ops: &'a HashMap<String, Op>,
+    depth: usize,
+) -> Option<u8> {
+    if let Some(&value) = values.get(name) {
+        return Some(value);
+    }
+
+    let (output, op) = ops.get_key_value(name)?;
+    if depth > ops.len() {
+        return None;
+    }
+
+    let (lhs, rhs) = op.inputs();
+    let l_value = evaluate(lhs, values, ops, depth + 1)?;
+    let r_value = evaluate(rhs, values, ops, depth + 1)?;
+
+    let value = match op {
+        Op::And(..) => l_value & r_value,
+        Op::Xor(..) => l_value ^ r_value,
+        Op::Or(..) => l_value | r_value,
+    };
+    values.insert(output, value);
+
+    Some(value)
+}
+
+/// Runs `x` and `y` through the gates and reads the sum off the z-wires.
+fn add(ops: &HashMap<String, Op>, bits: usize, x: u64, y: u64) -> Option<u64> {
+    let inputs: Vec<(String, u8)> = (0..bits)
+        .flat_map(|bit| {
+            [
+                (format!("x{:02}", bit), (x >> bit & 1) as u8),
+                (format!("y{:02}", bit), (y >> bit & 1) as u8),
+            ]
+        })
+        .collect();
+    let mut values: HashMap<&str, u8> = inputs
+        .iter()
+        .map(|(name, value)| (name.as_str(), *value))
+        .collect();
+
+    let mut sum = 0;
+    for bit in 0..=bits {
+        let value = evaluate(&format!("z{:02}", bit), &mut values, ops, 0)?;
+        sum |= (value as u64) << bit;
+    }
+
+    Some(sum)
+}
+
+/// A xorshift generator, to pick the numbers an adder is tested with.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Whether the gates add every pair of numbers tried, the longest carry
+/// chains and a batch of random ones.
+fn is_adder(ops: &HashMap<String, Op>, bits: usize) -> bool {
+    let mask = (1 << bits) - 1;
+    let mut random = Random(0x2024_1224);
+
+    let mut pairs = vec![(0, 0), (mask, 1), (1, mask), (mask, mask)];
+    pairs.extend((0..32).map(|_| (random.next() & mask, random.next() & mask)));
+
+    pairs
+        .into_iter()
+        .all(|(x, y)| add(ops, bits, x, y) == Some(x + y))
+}
+
+/// Finds the gate outputs that don't fit a ripple-carry adder. For every bit
+/// `i` it computes `a = x XOR y` and `b = x AND y`, the sum bit
+/// `z = a XOR carry` and the next carry `(a AND carry) OR b`. Bit 0 has no
+/// carry in and the last carry is the top z-wire.
+fn misplaced_outputs(ops: &HashMap<String, Op>, bits: usize) -> Vec<&str> {
+    let top_z = format!("z{:02}", bits);
+    let is_input = |wire: &str| wire.starts_with('x') || wire.starts_with('y');
+
+    let mut consumers: HashMap<&str, Vec<&Op>> = HashMap::new();
+    for op in ops.values() {
+        let (lhs, rhs) = op.inputs();
+        consumers.entry(lhs).or_default().push(op);
+        consumers.entry(rhs).or_default().push(op);
+    }
+    let feeds = |output: &str, is_kind: fn(&Op) -> bool| {
+        consumers
+            .get(output)
+            .is_some_and(|ops| ops.iter().any(|op| is_kind(op)))
+    };
+
+    let mut misplaced: Vec<&str> = ops
+        .iter()
+        .filter(|(output, op)| {
+            let (lhs, rhs) = op.inputs();
+            let first_bit = lhs.ends_with("00") && rhs.ends_with("00");
+
+            match op {
+                _ if output.starts_with('z') && **output != top_z => !matches!(op, Op::Xor(..)),
+                _ if **output == top_z => !matches!(op, Op::Or(..)),
+                Op::Xor(..) if !is_input(lhs) => true,
+                Op::Xor(..) => !first_bit && !feeds(output, |op| matches!(op, Op::Xor(..))),
+                Op::And(..) => !first_bit && !feeds(output, |op| matches!(op, Op::Or(..))),
+                Op::Or(..) => false,
+            }
+        })
+        .map(|(output, _)| output.as_str())
+        .collect();
+
+    misplaced.sort();
+    misplaced
+}
+
+/// Every way to split `wires` into pairs.
+fn pairings<'a>(wires: &[&'a str]) -> Vec<Vec<(&'a str, &'a str)>> {
+    let Some((&first, rest)) = wires.split_first() else {
+        return vec![Vec::new()];
+    };
+
+    let mut result = Vec::new();
+    for (index, &second) in rest.iter().enumerate() {
+        let others = [&rest[..index], &rest[index + 1..]].concat();
+
+        for mut pairing in pairings(&others) {
+            pairing.push((first, second));
+            result.push(pairing);
+        }
+    }
+
+    result
+}
+
+/// The sorted, comma-joined outputs of the four pairs of gates that were
+/// swapped, checked by swapping them back and simulating the adder.
+pub fn part2(device: &Device) -> Option<String> {
+    let bits = device
+        .values
+        .keys()
+        .filter(|name| name.starts_with('x'))
+        .count();
+    let misplaced = misplaced_outputs(&device.ops, bits);
+
+    if misplaced.len() != 8 {
+        return None;
+    }
+
+    pairings(&misplaced).into_iter().find_map(|pairing| {
+        let mut ops = device.ops.clone();
+
+        for (a, b) in pairing {
+            let (op_a, op_b) = (ops[a].clone(), ops[b].clone());
+            ops.insert(a.to_string(), op_b);
+            ops.insert(b.to_string(), op_a);
+        }
+
+        is_adder(&ops, bits).then(|| misplaced.join(","))
+    })
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 24;
+
+    type Input<'a> = Device;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day24>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    /// A ripple-carry adder for `bits` bits, with the outputs in `swaps`
+    /// exchanged.
+    fn adder(bits: usize, swaps: &[(&str, &str)]) -> String {
+        let mut lines = Vec::new();
+
+        for bit in 0..bits {
+            lines.push(format!("x{:02}: 0", bit));
+            lines.push(format!("y{:02}: 0", bit));
+        }
+        lines.push(String::new());
+
+        let rename = |wire: String| {
+            for &(a, b) in swaps {
+                if wire == a {
+                    return b.to_string();
+                }
+                if wire == b {
+                    return a.to_string();
+                }
+            }
+
+            wire
+        };
+        let carry = |bit: usize| match bit {
+            _ if bit == bits - 1 => format!("z{:02}", bits),
+            _ => format!("c{:02}", bit),
+        };
+        let mut gate = |lhs: String, op: &str, rhs: String, output: String| {
+            lines.push(format!("{} {} {} -> {}", lhs, op, rhs, rename(output)));
+        };
+
+        for bit in 0..bits {
+            let (x, y) = (format!("x{:02}", bit), format!("y{:02}", bit));
+
+            if bit == 0 {
+                gate(x.clone(), "XOR", y.clone(), "z00".to_string());
+                gate(x, "AND", y, carry(0));
+                continue;
+            }
+
+            let (a, b, t) = (
+                format!("a{:02}", bit),
+                format!("b{:02}", bit),
+                format!("t{:02}", bit),
+            );
+            gate(x.clone(), "XOR", y.clone(), a.clone());
+            gate(x, "AND", y, b.clone());
+            gate(a.clone(), "XOR", carry(bit - 1), format!("z{:02}", bit));
+            gate(a, "AND", carry(bit - 1), t.clone());
+            gate(b, "OR", t, carry(bit));
+        }
+
+        lines.join("\n")
+    }
+
+    #[test]
+    fn test_adder() {
+        let device = parse(&adder(8, &[])).unwrap();
+        assert!(is_adder(&device.ops, 8));
+        assert_eq!(None, part2(&device));
+    }
+
+    #[test]
+    fn test_part2() {
+        let swaps = [
+            ("a01", "b01"),
+            ("z02", "t02"),
+            ("z03", "c03"),
+            ("z05", "b05"),
+        ];
+        let device = parse(&adder(8, &swaps)).unwrap();
+
+        assert!(!is_adder(&device.ops, 8));
+        assert_eq!(
+            Some("a01,b01,b05,c03,t02,z02,z03,z05".to_string()),
+            part2(&device)
+        );
+    }
+}
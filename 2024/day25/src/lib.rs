@@ -0,0 +1,173 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+const COLUMNS: usize = 5;
+/// The rows between the solid row at the base and the empty one opposite.
+const SPACE: usize = 5;
+
+/// The pin heights of a lock or the cut heights of a key, column by column.
+///
+/// `mask` fills, for each column, a field of `SPACE` bits in unary from the
+/// side the schematic grows from: from the top for a lock, from the bottom
+/// for a key. A key fits a lock exactly when the two masks share no bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Profile {
+    heights: [usize; COLUMNS],
+    mask: u32,
+}
+
+impl Profile {
+    fn lock(heights: [usize; COLUMNS]) -> Self {
+        Self::new(heights, |height| (1 << height) - 1)
+    }
+
+    fn key(heights: [usize; COLUMNS]) -> Self {
+        Self::new(heights, |height| ((1 << height) - 1) << (SPACE - height))
+    }
+
+    fn new(heights: [usize; COLUMNS], field: impl Fn(usize) -> u32) -> Self {
+        let mask = heights
+            .iter()
+            .enumerate()
+            .fold(0, |mask, (column, &height)| {
+                mask | field(height) << (column * SPACE)
+            });
+
+        Self { heights, mask }
+    }
+
+    pub fn fits(&self, other: &Profile) -> bool {
+        self.mask & other.mask == 0
+    }
+}
+
+enum Schematic {
+    Lock(Profile),
+    Key(Profile),
+}
+
+pub struct Schematics {
+    locks: Vec<Profile>,
+    keys: Vec<Profile>,
+}
+
+/// Measures each column of a schematic, with `rows` ordered from the solid
+/// base row to the empty opposite one.
+fn column_heights(rows: &[&str]) -> Result<[usize; COLUMNS], ParseError> {
+    let mut heights = [0; COLUMNS];
+
+    for (column, height) in heights.iter_mut().enumerate() {
+        *height = rows[1..]
+            .iter()
+            .take_while(|row| row.as_bytes()[column] == b'#')
+            .count();
+
+        if let Some(row) = rows[1 + *height..]
+            .iter()
+            .find(|row| row.as_bytes()[column] == b'#')
+        {
+            return Err(ParseError::new(&row[column..column + 1], "'.'"));
+        }
+    }
+
+    Ok(heights)
+}
+
+fn parse_schematic(block: &str) -> Result<Schematic, ParseError> {
+    let mut rows: Vec<&str> = block.lines().collect();
+
+    if rows.len() != SPACE + 2 {
+        return Err(ParseError::after(
+            block.trim_end(),
+            format!("{} rows", SPACE + 2),
+        ));
+    }
+
+    for row in &rows {
+        parse::check_chars(row, |c| c == '#' || c == '.', "'#' or '.'")?;
+        if row.len() != COLUMNS {
+            return Err(ParseError::new(row, format!("{} columns", COLUMNS)));
+        }
+    }
+
+    let solid = "#".repeat(COLUMNS);
+    let empty = ".".repeat(COLUMNS);
+
+    if rows[0] == solid && rows[SPACE + 1] == empty {
+        Ok(Schematic::Lock(Profile::lock(column_heights(&rows)?)))
+    } else if rows[0] == empty && rows[SPACE + 1] == solid {
+        rows.reverse();
+        Ok(Schematic::Key(Profile::key(column_heights(&rows)?)))
+    } else {
+        Err(ParseError::new(rows[0], "a lock or a key"))
+    }
+}
+
+pub fn parse(data: &str) -> Result<Schematics, ParseError> {
+    let mut schematics = Schematics {
+        locks: Vec::new(),
+        keys: Vec::new(),
+    };
+
+    for block in data.split("\n\n") {
+        match parse_schematic(block)? {
+            Schematic::Lock(lock) => schematics.locks.push(lock),
+            Schematic::Key(key) => schematics.keys.push(key),
+        }
+    }
+
+    Ok(schematics)
+}
+
+pub fn part1(schematics: &Schematics) -> usize {
+    schematics
+        .locks
+        .iter()
+        .map(|lock| schematics.keys.iter().filter(|key| lock.fits(key)).count())
+        .sum()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 25;
+
+    type Input<'a> = Schematics;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day25>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_profiles() {
+        let schematics = parse(include_str!("../fixtures/example.txt")).unwrap();
+
+        let locks: Vec<_> = schematics.locks.iter().map(|lock| lock.heights).collect();
+        let keys: Vec<_> = schematics.keys.iter().map(|key| key.heights).collect();
+        assert_eq!(vec![[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]], locks);
+        assert_eq!(
+            vec![[5, 0, 2, 1, 3], [4, 3, 4, 0, 2], [3, 0, 2, 0, 1]],
+            keys
+        );
+
+        let lock = schematics.locks[0];
+        assert!(!lock.fits(&schematics.keys[0]));
+        assert!(!lock.fits(&schematics.keys[1]));
+        assert!(lock.fits(&schematics.keys[2]));
+    }
+}
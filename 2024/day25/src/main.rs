@@ -0,0 +1,9 @@
+use aoc2024_day25::{parse, part1};
+use aoc_common::input;
+
+fn main() {
+    let data = input::load_or_exit(2024, 25);
+    let schematics = input::parse_or_exit(&data, parse);
+
+    println!("Day 25 Part 1: {}", part1(&schematics));
+}
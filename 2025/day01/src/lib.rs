@@ -0,0 +1,91 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+fn parse_steps(lines: &[&str]) -> Result<Vec<i32>, ParseError> {
+    lines
+        .iter()
+        .map(|line| {
+            if let Some(number) = line.strip_prefix('R') {
+                parse::number::<i32>(number)
+            } else {
+                Ok(-parse::number::<i32>(parse::strip_prefix(line, "L")?)?)
+            }
+        })
+        .collect()
+}
+
+pub fn part1(steps: &[i32]) -> i32 {
+    let mut position = 50;
+    let mut zero_counter = 0;
+
+    for step in steps {
+        position += step;
+        position %= 100;
+
+        if position == 0 {
+            zero_counter += 1;
+        }
+    }
+
+    zero_counter
+}
+
+pub fn part2(steps: &[i32]) -> i32 {
+    let mut position = 50;
+    let mut zero_counter = 0;
+
+    for step in steps {
+        let small_step = step % 100;
+        zero_counter += ((step - small_step) / 100).abs();
+        let new_position = position + small_step;
+
+        if position != 0 && !(0..100).contains(&new_position) {
+            zero_counter += 1;
+        }
+
+        if new_position == 0 {
+            zero_counter += 1;
+        }
+
+        position = (new_position + 100) % 100;
+    }
+
+    zero_counter
+}
+
+pub fn parse(data: &str) -> Result<Vec<i32>, ParseError> {
+    let lines = data.lines().collect::<Vec<_>>();
+
+    parse_steps(&lines)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i32>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day01>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -1,63 +1,10 @@
-fn parse_steps(lines: &Vec<&str>) -> Vec<i32> {
-    lines
-        .iter()
-        .map(|line| {
-            let (dir, number) = line.split_at(1);
-            let number = number.parse::<i32>().unwrap();
-
-            if dir == "R" { number } else { number * -1 }
-        })
-        .collect()
-}
-
-fn part1(steps: &Vec<i32>) -> i32 {
-    let mut position = 50;
-    let mut zero_counter = 0;
-
-    for step in steps {
-        position += step;
-        position %= 100;
-
-        if position == 0 {
-            zero_counter += 1;
-        }
-    }
-
-    zero_counter
-}
-
-fn part2(steps: &Vec<i32>) -> i32 {
-    let mut position = 50;
-    let mut zero_counter = 0;
-
-    for step in steps {
-        let small_step = step % 100;
-        zero_counter += ((step - small_step) / 100).abs();
-        let new_position = position + small_step;
-
-        if position != 0 {
-            if new_position < 0 {
-                zero_counter += 1;
-            } else if new_position >= 100 {
-                zero_counter += 1;
-            }
-        }
-
-        if new_position == 0 {
-            zero_counter += 1;
-        }
-
-        position = (new_position + 100) % 100;
-    }
-
-    zero_counter
-}
+use aoc_common::input;
+use aoc2025_day01::{parse, part1, part2};
 
 fn main() {
-    let data = include_str!("../day01.txt");
+    let data = input::load_or_exit(2025, 1);
 
-    let lines = data.lines().collect();
-    let steps = parse_steps(&lines);
+    let steps = input::parse_or_exit(&data, parse);
 
     let result_part1 = part1(&steps);
 
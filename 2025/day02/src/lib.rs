@@ -0,0 +1,133 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+fn calculate_id(n: u64, num_repeats: usize) -> u64 {
+    let mag = 10_u64.pow(n.checked_ilog10().unwrap_or(0) + 1);
+
+    let mut result: u64 = n;
+    for _ in 1..num_repeats {
+        result = result * mag + n;
+    }
+
+    result
+}
+
+fn normalize_ranges(ranges: Vec<(&str, &str)>) -> Vec<(String, String)> {
+    let mut normalized_ranges: Vec<(String, String)> = Vec::new();
+
+    for (start, end) in ranges {
+        assert!(end.len() - start.len() < 2);
+
+        if end.len() - start.len() == 0 {
+            normalized_ranges.push((start.to_string(), end.to_string()));
+        } else {
+            let split_point = 10_u64.pow(end.len() as u32 - 1);
+
+            normalized_ranges.push((split_point.to_string(), end.to_string()));
+            normalized_ranges.push((start.to_string(), (split_point - 1).to_string()));
+        }
+    }
+
+    normalized_ranges
+}
+
+fn find_ids(start: &str, end: &str, num_repeats: usize, ids: &mut HashSet<u64>) {
+    if !start.len().is_multiple_of(num_repeats) {
+        return;
+    }
+
+    let pattern_len = start.len() / num_repeats;
+    let range = (start.parse::<u64>().unwrap())..=(end.parse::<u64>().unwrap());
+    let start_part = start[..pattern_len].parse::<u64>().unwrap();
+    let end_part = end[..pattern_len].parse::<u64>().unwrap();
+
+    for n in start_part..=end_part {
+        let id = calculate_id(n, num_repeats);
+
+        if range.contains(&id) {
+            ids.insert(id);
+        }
+    }
+}
+
+fn parse_range(range: &str) -> Result<(&str, &str), ParseError> {
+    let (start, end) = parse::split_once(range, "-")?;
+
+    for id in [start, end] {
+        parse::check_chars(id, |c| c.is_ascii_digit(), "a digit")?;
+        parse::number::<u64>(id)?;
+    }
+
+    if end.len() < start.len() || end.len() > start.len() + 1 {
+        return Err(ParseError::new(
+            end,
+            "an end with as many digits as the start, or one more",
+        ));
+    }
+
+    Ok((start, end))
+}
+
+pub fn parse(data: &str) -> Result<Vec<(String, String)>, ParseError> {
+    let ranges = data
+        .trim()
+        .split(',')
+        .map(parse_range)
+        .collect::<Result<Vec<(&str, &str)>, _>>()?;
+
+    Ok(normalize_ranges(ranges))
+}
+
+pub fn part1(ranges: &[(String, String)]) -> u64 {
+    let mut result: HashSet<u64> = HashSet::new();
+
+    for (start, end) in ranges {
+        find_ids(start, end, 2, &mut result);
+    }
+
+    result.iter().sum::<u64>()
+}
+
+pub fn part2(ranges: &[(String, String)]) -> u64 {
+    let mut result: HashSet<u64> = HashSet::new();
+
+    for (start, end) in ranges {
+        for num_repeats in 2..=start.len() {
+            find_ids(start, end, num_repeats, &mut result);
+        }
+    }
+
+    result.iter().sum::<u64>()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<(String, String)>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+
+fn find_largest(bank: &[char]) -> (char, usize) {
+    assert!(!bank.is_empty());
+    let mut largest = bank[0];
+    let mut index = 0;
+
+    for (i, &bat) in bank.iter().enumerate() {
+        if bat > largest {
+            largest = bat;
+            index = i;
+        }
+    }
+
+    (largest, index)
+}
+
+fn parse_bank(line: &str) -> Result<Vec<char>, ParseError> {
+    let bank = parse::check_chars(line, |c| c.is_ascii_digit(), "a battery's joltage")?;
+
+    if bank.len() < 12 {
+        return Err(ParseError::new(bank, "at least 12 batteries"));
+    }
+
+    Ok(bank.chars().collect::<Vec<char>>())
+}
+
+pub fn parse(data: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    data.lines().map(parse_bank).collect()
+}
+
+pub fn part1(banks: &[Vec<char>]) -> u64 {
+    let mut sum = 0_u64;
+
+    for bank in banks {
+        assert!(bank.len() >= 2);
+        let (first, index) = find_largest(&bank[..bank.len() - 1]);
+        let (second, _) = find_largest(&bank[index + 1..]);
+
+        sum += first.to_digit(10).unwrap() as u64 * 10 + second.to_digit(10).unwrap() as u64;
+    }
+
+    sum
+}
+
+pub fn part2(banks: &[Vec<char>]) -> u64 {
+    let mut sum = 0_u64;
+
+    let mut bats: Vec<char> = Vec::new();
+    let mut current_index;
+
+    for bank in banks {
+        bats.clear();
+        current_index = 0;
+
+        for offset in (0..12).rev() {
+            let (bat, index) = find_largest(&bank[current_index..bank.len() - offset]);
+            bats.push(bat);
+            current_index += index + 1;
+        }
+
+        sum += bats.iter().collect::<String>().parse::<u64>().unwrap();
+    }
+
+    sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Vec<char>>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day03>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use aoc_grid::{Grid, Point};
+
+#[derive(PartialEq, Clone)]
+pub enum Tile {
+    Empty,
+    Roll,
+}
+
+impl Tile {
+    fn parse(token: &str) -> Result<Self, ParseError> {
+        match token {
+            "." => Ok(Self::Empty),
+            "@" => Ok(Self::Roll),
+            _ => Err(ParseError::new(token, "'.' or '@'")),
+        }
+    }
+}
+
+pub type Map = Grid<Tile>;
+
+fn find_removable_rolls(map: &Map, removable_rolls: &mut Vec<Point>) {
+    for (point, tile) in map.iter() {
+        if tile == &Tile::Empty {
+            continue;
+        }
+
+        let neighbour_count = map
+            .neighbors8(point)
+            .filter(|&neighbour| map[neighbour] == Tile::Roll)
+            .count();
+
+        if neighbour_count < 4 {
+            removable_rolls.push(point);
+        }
+    }
+}
+
+pub fn parse(data: &str) -> Result<Map, ParseError> {
+    Grid::try_parse(data, Tile::parse)
+}
+
+pub fn part1(map: &Map) -> usize {
+    let mut accessable_rolls: Vec<Point> = Vec::new();
+    find_removable_rolls(map, &mut accessable_rolls);
+
+    accessable_rolls.len()
+}
+
+pub fn part2(map: &Map) -> usize {
+    let mut map = map.clone();
+
+    let mut accessable_rolls: Vec<Point> = Vec::new();
+    find_removable_rolls(&map, &mut accessable_rolls);
+
+    let mut total_remove_count = accessable_rolls.len();
+
+    while !accessable_rolls.is_empty() {
+        for p in accessable_rolls.drain(..) {
+            map[p] = Tile::Empty;
+        }
+
+        find_removable_rolls(&map, &mut accessable_rolls);
+        total_remove_count += accessable_rolls.len();
+    }
+
+    total_remove_count
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Map;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,122 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::{cmp::max, cmp::min, collections::HashSet, ops::RangeInclusive};
+
+fn parse_input(data: &str) -> Result<(Vec<RangeInclusive<usize>>, Vec<usize>), ParseError> {
+    let (range_data, id_data) = parse::split_once(data, "\n\n")?;
+
+    let ranges: Vec<RangeInclusive<usize>> = range_data
+        .lines()
+        .map(|line| {
+            let (lo, hi) = parse::split_once(line, "-")?;
+            Ok(parse::number::<usize>(lo)?..=parse::number::<usize>(hi)?)
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let ids: Vec<usize> = id_data
+        .lines()
+        .map(parse::number::<usize>)
+        .collect::<Result<_, _>>()?;
+
+    Ok((ranges, ids))
+}
+
+fn intersect(
+    r1: &RangeInclusive<usize>,
+    r2: &RangeInclusive<usize>,
+) -> Option<RangeInclusive<usize>> {
+    if r1.contains(r2.start()) || r2.contains(r1.start()) {
+        return Some(min(*r1.start(), *r2.start())..=max(*r1.end(), *r2.end()));
+    }
+
+    None
+}
+
+pub struct Inventory {
+    ranges: Vec<RangeInclusive<usize>>,
+    ids: Vec<usize>,
+}
+
+pub fn parse(data: &str) -> Result<Inventory, ParseError> {
+    let (mut ranges, ids) = parse_input(data)?;
+
+    let mut final_ranges: Vec<RangeInclusive<usize>> = Vec::new();
+
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+        for range in ranges.drain(..) {
+            let mut found = false;
+            for final_range in final_ranges.iter_mut() {
+                if let Some(new_range) = intersect(&range, final_range) {
+                    *final_range = new_range;
+                    found = true;
+                    changed = true;
+                    break;
+                }
+            }
+            if !found {
+                final_ranges.push(range);
+            }
+        }
+
+        std::mem::swap(&mut ranges, &mut final_ranges);
+    }
+
+    Ok(Inventory { ranges, ids })
+}
+
+pub fn part1(inventory: &Inventory) -> usize {
+    let mut fresh_ids: HashSet<usize> = HashSet::new();
+
+    for &id in &inventory.ids {
+        for range in &inventory.ranges {
+            if range.contains(&id) {
+                fresh_ids.insert(id);
+                break;
+            }
+        }
+    }
+
+    fresh_ids.len()
+}
+
+pub fn part2(inventory: &Inventory) -> usize {
+    inventory
+        .ranges
+        .iter()
+        .map(|range| range.end() - range.start() + 1)
+        .sum::<usize>()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input<'a> = Inventory;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day05>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
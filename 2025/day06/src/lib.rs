@@ -0,0 +1,213 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::ops::Range;
+
+#[derive(PartialEq)]
+enum Op {
+    Add,
+    Mul,
+}
+
+impl Op {
+    fn from_str(s: &str) -> Option<Self> {
+        match s.trim() {
+            "+" => Some(Self::Add),
+            "*" => Some(Self::Mul),
+            _ => None,
+        }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '+' => Some(Self::Add),
+            '*' => Some(Self::Mul),
+            _ => None,
+        }
+    }
+}
+
+struct Group {
+    op: Op,
+    nums: Vec<u64>,
+}
+
+impl Group {
+    fn execute(&self) -> u64 {
+        match self.op {
+            Op::Add => self.nums.iter().sum(),
+            Op::Mul => self.nums.iter().product(),
+        }
+    }
+}
+
+fn parse_part1(input: &str) -> Result<Vec<Group>, ParseError> {
+    let first_line = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::after(input, "a worksheet"))?;
+
+    let col_count = first_line.split_whitespace().count();
+
+    let mut data: Vec<Group> = Vec::with_capacity(col_count);
+
+    for _ in 0..col_count {
+        data.push(Group {
+            op: Op::Add,
+            nums: Vec::new(),
+        });
+    }
+
+    for line in input.lines() {
+        let cols = line.split_whitespace().collect::<Vec<_>>();
+        if cols.len() != col_count {
+            return Err(ParseError::new(line, format!("{} columns", col_count)));
+        }
+
+        for (index, col) in cols.into_iter().enumerate() {
+            match Op::from_str(col) {
+                Some(op) => data[index].op = op,
+                None => data[index].nums.push(parse::number::<u64>(col)?),
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+fn parse_part2(input: &str) -> Result<Vec<Group>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Some((ops_str, number_lines)) = lines.split_last().filter(|(_, rest)| !rest.is_empty())
+    else {
+        return Err(ParseError::after(
+            input,
+            "rows of numbers and a row of operators",
+        ));
+    };
+
+    if !ops_str.starts_with(['+', '*']) {
+        return Err(ParseError::new(ops_str, "an operator in the first column"));
+    }
+    parse::check_chars(ops_str, |c| " +*".contains(c), "'+' or '*'")?;
+
+    for line in number_lines {
+        parse::check_chars(line, |c| c == ' ' || c.is_ascii_digit(), "a digit")?;
+
+        if line.len() < ops_str.len() {
+            return Err(ParseError::after(
+                line,
+                format!("{} columns", ops_str.len()),
+            ));
+        }
+    }
+
+    let data: Vec<Vec<char>> = lines
+        .iter()
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .collect();
+
+    let ops_line = &data[data.len() - 1];
+    let mut groups: Vec<Group> = Vec::new();
+    let mut group_ranges: Vec<Range<usize>> = Vec::new();
+    let mut last_op_index = 0;
+
+    for (index, &c) in ops_line.iter().enumerate() {
+        if let Some(op) = Op::from_char(c) {
+            if index > 0 {
+                group_ranges.push(last_op_index..index - 1);
+                last_op_index = index;
+            }
+            groups.push(Group {
+                op,
+                nums: Vec::new(),
+            });
+        }
+    }
+
+    group_ranges.push(last_op_index..ops_line.len());
+
+    for (group_index, range) in group_ranges.iter().enumerate() {
+        let mut nums: Vec<Vec<char>> = Vec::with_capacity(range.len());
+
+        for _ in range.clone() {
+            nums.push(Vec::new());
+        }
+
+        for line in data[0..data.len() - 1].iter() {
+            for (num_index, data_index) in range.clone().enumerate() {
+                nums[num_index].push(line[data_index]);
+            }
+        }
+
+        for (num, data_index) in nums.iter().zip(range.clone()) {
+            let num = num.iter().collect::<String>();
+            let num = num.trim().parse::<u64>().map_err(|_| {
+                // Point at the column in the row of operators.
+                ParseError::new(
+                    &ops_str[data_index..data_index + 1],
+                    "a number in this column",
+                )
+            })?;
+
+            groups[group_index].nums.push(num);
+        }
+    }
+
+    Ok(groups)
+}
+
+/// The worksheet read row by row, and read column by column right to
+/// left.
+pub struct Worksheet {
+    by_rows: Vec<Group>,
+    by_columns: Vec<Group>,
+}
+
+pub fn parse(data: &str) -> Result<Worksheet, ParseError> {
+    Ok(Worksheet {
+        by_rows: parse_part1(data)?,
+        by_columns: parse_part2(data)?,
+    })
+}
+
+pub fn part1(worksheet: &Worksheet) -> u64 {
+    worksheet.by_rows.iter().map(|group| group.execute()).sum()
+}
+
+pub fn part2(worksheet: &Worksheet) -> u64 {
+    worksheet
+        .by_columns
+        .iter()
+        .map(|group| group.execute())
+        .sum()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Worksheet;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day06>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,155 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use aoc_grid::{Direction, Grid, Point};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(PartialEq, Clone, Debug)]
+enum Tile {
+    Empty,
+    Splitter,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Self {
+        match c {
+            '.' | 'S' => Self::Empty,
+            '^' => Self::Splitter,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub struct Map {
+    tiles: Grid<Tile>,
+    start: Point,
+}
+
+fn parse_tile(token: &str) -> Result<char, ParseError> {
+    match token.chars().next() {
+        Some(c @ ('.' | '^' | 'S')) => Ok(c),
+        _ => Err(ParseError::new(token, "'.', '^' or 'S'")),
+    }
+}
+
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let chars = Grid::try_parse(input, parse_tile)?;
+    let start = chars
+        .position(|&c| c == 'S')
+        .ok_or_else(|| ParseError::after(input, "a start 'S'"))?;
+
+    Ok(Map {
+        tiles: chars.map(|&c| Tile::from_char(c)),
+        start,
+    })
+}
+
+pub fn part1(map: &Map) -> u64 {
+    let mut next: VecDeque<Point> = VecDeque::new();
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut split_count = 0;
+
+    next.push_back(map.start);
+
+    while let Some(point) = next.pop_front() {
+        if visited.contains(&point) {
+            continue;
+        }
+
+        let Some(tile) = map.tiles.get(point) else {
+            continue;
+        };
+
+        visited.insert(point);
+
+        match tile {
+            Tile::Empty => next.push_back(point.step(Direction::South)),
+            Tile::Splitter => {
+                split_count += 1;
+                next.push_back(point.step(Direction::West));
+                next.push_back(point.step(Direction::East));
+            }
+        }
+    }
+
+    split_count
+}
+
+fn collect_splits(map: &Map) -> Vec<Point> {
+    map.tiles
+        .iter()
+        .filter(|(_, tile)| *tile == &Tile::Splitter)
+        .map(|(point, _)| point)
+        .collect()
+}
+
+fn count_splits(map: &Map, counted_splits: &HashMap<Point, u64>, split: &Point) -> u64 {
+    let mut split_count = 0;
+
+    'outer: for x in [split.x - 1, split.x + 1] {
+        for y in split.y..map.tiles.height() as i32 {
+            let point = Point::new(x, y);
+            if let Some(sub_count) = counted_splits.get(&point) {
+                split_count += sub_count;
+                continue 'outer;
+            }
+        }
+        split_count += 1;
+    }
+
+    split_count
+}
+
+pub fn part2(map: &Map) -> u64 {
+    let mut splits = collect_splits(map);
+    let mut counted_splits: HashMap<Point, u64> = HashMap::new();
+
+    while let Some(split) = splits.pop() {
+        let count = count_splits(map, &counted_splits, &split);
+        counted_splits.insert(split, count);
+    }
+
+    for y in map.start.y..map.tiles.height() as i32 {
+        let point = Point::new(map.start.x, y);
+
+        if map.tiles[point] == Tile::Splitter {
+            return *counted_splits.get(&point).unwrap();
+        }
+    }
+
+    1
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    parse_input(input)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Map;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day07>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
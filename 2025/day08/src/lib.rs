@@ -0,0 +1,197 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Params, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Point {
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let [x, y, z] = parse::split_n::<3>(line, ",")?;
+
+        Ok(Self {
+            x: parse::number(x)?,
+            y: parse::number(y)?,
+            z: parse::number(z)?,
+        })
+    }
+
+    fn distance(&self, other: &Self) -> f64 {
+        let x = (self.x - other.x) as i64;
+        let y = (self.y - other.y) as i64;
+        let z = (self.z - other.z) as i64;
+
+        ((x.pow(2) + y.pow(2) + z.pow(2)) as f64).sqrt()
+    }
+}
+
+#[derive(Debug)]
+struct Edge<'a> {
+    point1: &'a Point,
+    point2: &'a Point,
+    distance: f64,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Point>, ParseError> {
+    let points = input
+        .lines()
+        .map(Point::from_line)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Connecting everything needs at least one connection.
+    if points.len() < 2 {
+        return Err(ParseError::after(input, "at least two junction boxes"));
+    }
+
+    Ok(points)
+}
+
+fn calculate_edges(points: &[Point]) -> Vec<Edge<'_>> {
+    let mut edges: Vec<Edge> = Vec::new();
+
+    for i in 0..points.len() - 1 {
+        for j in i + 1..points.len() {
+            let distance = points[i].distance(&points[j]);
+            edges.push(Edge {
+                point1: &points[i],
+                point2: &points[j],
+                distance,
+            });
+        }
+    }
+
+    edges.sort_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap());
+
+    edges
+}
+
+fn find_cluster_index(clusters: &Vec<HashSet<&Point>>, point: &Point) -> Option<usize> {
+    for (index, cluster) in clusters.iter().enumerate() {
+        if cluster.contains(point) {
+            return Some(index);
+        }
+    }
+
+    None
+}
+
+fn make_connection<'a>(edge: &'a Edge, clusters: &mut Vec<HashSet<&'a Point>>) {
+    let cluster1_index = find_cluster_index(clusters, edge.point1);
+    let cluster2_index = find_cluster_index(clusters, edge.point2);
+
+    match (cluster1_index, cluster2_index) {
+        (Some(i1), Some(i2)) if i1 != i2 => {
+            let keep_index = i1.min(i2);
+            let remove_index = i1.max(i2);
+            let remove_cluster = clusters.remove(remove_index);
+            clusters[keep_index].extend(remove_cluster);
+        }
+        (Some(_), Some(_)) => {}
+        (Some(i1), None) => {
+            clusters[i1].insert(edge.point2);
+        }
+        (None, Some(i2)) => {
+            clusters[i2].insert(edge.point1);
+        }
+        (None, None) => {
+            clusters.push(HashSet::from([edge.point1, edge.point2]));
+        }
+    }
+}
+
+fn connect_closest(edges: &[Edge], connection_count: usize) -> usize {
+    let mut clusters: Vec<HashSet<&Point>> = Vec::new();
+
+    for edge in edges.iter().take(connection_count) {
+        make_connection(edge, &mut clusters);
+    }
+
+    clusters.sort_by_key(|c| std::cmp::Reverse(c.len()));
+
+    clusters.iter().take(3).map(|c| c.len()).product::<usize>()
+}
+
+fn connect_all(edges: &[Edge], points: &[Point]) -> usize {
+    let mut clusters: Vec<HashSet<&Point>> = Vec::new();
+    let mut connected_points: HashSet<&Point> = HashSet::with_capacity(points.len());
+    let mut last_edge: Option<&Edge> = None;
+
+    for edge in edges {
+        make_connection(edge, &mut clusters);
+
+        connected_points.insert(edge.point1);
+        connected_points.insert(edge.point2);
+
+        if clusters.len() == 1 && connected_points.len() == points.len() {
+            last_edge = Some(edge);
+            break;
+        }
+    }
+
+    let last_edge = last_edge.unwrap();
+
+    last_edge.point1.x as usize * last_edge.point2.x as usize
+}
+
+pub fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    parse_input(input)
+}
+
+pub fn part1(points: &[Point], connections: usize) -> usize {
+    let edges = calculate_edges(points);
+
+    connect_closest(&edges, connections)
+}
+
+pub fn part2(points: &[Point]) -> usize {
+    let edges = calculate_edges(points);
+
+    connect_all(&edges, points)
+}
+
+pub struct Day08;
+
+pub struct Playground {
+    points: Vec<Point>,
+    connections: usize,
+}
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Input<'a> = Playground;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(data, &Params::default())
+    }
+
+    fn parse_with<'a>(data: &'a str, params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok(Playground {
+            points: parse(data)?,
+            connections: params.get("connections", 1000) as usize,
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(&input.points, input.connections).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(&input.points).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day08>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
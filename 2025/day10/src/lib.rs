@@ -0,0 +1,384 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use aoc_math::{gcd, lcm_all};
+use std::cmp::Ordering;
+
+pub struct Machine {
+    pattern: u64,
+    buttons: Vec<u64>,
+    joltages: Vec<u64>,
+}
+
+fn parse_group<'a>(object: &'a str, open: &str, close: &str) -> Result<&'a str, ParseError> {
+    parse::strip_suffix(parse::strip_prefix(object, open)?, close)
+}
+
+fn parse_machine(line: &str) -> Result<Machine, ParseError> {
+    let mut objects = line.split_whitespace();
+
+    let lights = objects
+        .next()
+        .ok_or_else(|| ParseError::after(line, "an indicator light diagram"))?;
+    let lights = parse_group(lights, "[", "]")?;
+    parse::check_chars(lights, |c| c == '.' || c == '#', "'.' or '#'")?;
+
+    if lights.is_empty() || lights.len() > 64 {
+        return Err(ParseError::new(lights, "between 1 and 64 lights"));
+    }
+
+    let mut pattern = 0;
+    for c in lights.chars().rev() {
+        pattern = (pattern << 1) + u64::from(c == '#');
+    }
+
+    let mut buttons: Vec<u64> = Vec::new();
+    let mut joltages = None;
+
+    for object in objects {
+        if joltages.is_some() {
+            return Err(ParseError::new(object, "end of line"));
+        }
+
+        if object.starts_with('{') {
+            joltages = Some(parse_group(object, "{", "}")?);
+            continue;
+        }
+
+        let mut button = 0;
+        for light in parse_group(object, "(", ")")?.split(',') {
+            let light = parse::number::<usize>(light)?;
+            if light >= lights.len() {
+                return Err(ParseError::new(object, "a light in the diagram"));
+            }
+
+            button |= 1 << light;
+        }
+
+        buttons.push(button);
+    }
+
+    let requirements = joltages.ok_or_else(|| ParseError::after(line, "joltage requirements"))?;
+    let joltages = requirements
+        .split(',')
+        .map(parse::number::<u64>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if joltages.len() != lights.len() {
+        return Err(ParseError::new(
+            requirements,
+            format!("{} joltages", lights.len()),
+        ));
+    }
+
+    Ok(Machine {
+        pattern,
+        buttons,
+        joltages,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input.lines().map(parse_machine).collect()
+}
+
+fn push_button_lights(pattern: u64, buttons: &[u64], count: u64) -> bool {
+    if count == 0 {
+        return false;
+    }
+
+    for button in buttons {
+        if pattern == *button {
+            return true;
+        }
+
+        if push_button_lights(pattern ^ button, buttons, count - 1) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Brings the augmented matrix `rows` into reduced row echelon form, using
+/// only integers: every row operation cross-multiplies and then divides the
+/// row by the gcd of its entries, so nothing is rounded and the numbers stay
+/// small. Returns the pivot column of every row that has one, or `None` if
+/// the equations contradict each other.
+fn row_reduce(rows: &mut [Vec<i64>]) -> Option<Vec<usize>> {
+    let columns = rows.first().map_or(0, |row| row.len() - 1);
+    let mut pivots = Vec::new();
+
+    for column in 0..columns {
+        let row = pivots.len();
+        let Some(pivot) = (row..rows.len()).find(|&r| rows[r][column] != 0) else {
+            continue;
+        };
+        rows.swap(row, pivot);
+
+        let pivot_row = rows[row].clone();
+        for (index, other) in rows.iter_mut().enumerate() {
+            let factor = other[column];
+
+            if index != row && factor != 0 {
+                for (value, &pivot_value) in other.iter_mut().zip(&pivot_row) {
+                    *value = *value * pivot_row[column] - pivot_value * factor;
+                }
+
+                let divisor = other.iter().fold(0, |divisor, &value| gcd(divisor, value));
+                if divisor > 1 {
+                    other.iter_mut().for_each(|value| *value /= divisor);
+                }
+            }
+        }
+
+        pivots.push(column);
+    }
+
+    // What's left below the pivots reads 0 = constant.
+    if rows[pivots.len()..].iter().any(|row| row[columns] != 0) {
+        return None;
+    }
+
+    Some(pivots)
+}
+
+/// The fewest presses that bring every counter to its joltage.
+///
+/// With `x` the presses of each button, that's the integer program
+/// "minimise the sum of `x` subject to `A x = b` and `x >= 0`", where `A`
+/// says which counters each button adds to and `b` holds the joltages.
+/// Elimination expresses the pivot buttons in the remaining free ones, and
+/// the free buttons are searched up to the lowest joltage they add to.
+fn fewest_presses(machine: &Machine) -> Option<u64> {
+    let counters = machine.joltages.len();
+    let buttons = machine.buttons.len();
+
+    let mut rows: Vec<Vec<i64>> = (0..counters)
+        .map(|counter| {
+            let mut row: Vec<i64> = machine
+                .buttons
+                .iter()
+                .map(|button| (button >> counter & 1) as i64)
+                .collect();
+            row.push(machine.joltages[counter] as i64);
+            row
+        })
+        .collect();
+
+    let pivots = row_reduce(&mut rows)?;
+    for (row, &column) in rows.iter_mut().zip(&pivots) {
+        if row[column] < 0 {
+            row.iter_mut().for_each(|value| *value = -*value);
+        }
+    }
+
+    let free: Vec<usize> = (0..buttons)
+        .filter(|button| !pivots.contains(button))
+        .collect();
+    let limits: Vec<i64> = free
+        .iter()
+        .map(|&button| {
+            (0..counters)
+                .filter(|&counter| machine.buttons[button] >> counter & 1 == 1)
+                .map(|counter| machine.joltages[counter] as i64)
+                .min()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let rows = &rows[..pivots.len()];
+    let scale = lcm_all(rows.iter().zip(&pivots).map(|(row, &column)| row[column]));
+    let shares: Vec<i64> = rows
+        .iter()
+        .zip(&pivots)
+        .map(|(row, &column)| scale / row[column])
+        .collect();
+    let weights = free
+        .iter()
+        .map(|&button| {
+            let pivot_presses: i64 = rows
+                .iter()
+                .zip(&shares)
+                .map(|(row, share)| row[button] * share)
+                .sum();
+            scale - pivot_presses
+        })
+        .collect();
+
+    let mut search = FreeSearch {
+        rows,
+        pivots: &pivots,
+        free: &free,
+        limits: &limits,
+        scale,
+        shares,
+        weights,
+        best: None,
+    };
+    let constants: Vec<i64> = rows.iter().map(|row| row[buttons]).collect();
+    search.run(0, constants, 0);
+
+    search.best.map(|best| best as u64)
+}
+
+/// Tries every number of presses for the free buttons, one button at a
+/// time, and keeps the lowest total for which every pivot button gets a
+/// whole, non-negative number of presses.
+///
+/// The total is linear in the free presses, so it's tracked times `scale`,
+/// the lcm of the pivots, to stay in integers: a pivot button is pressed
+/// `constant * share / scale` times, and each press of a free button
+/// changes the total by `weight / scale`.
+struct FreeSearch<'a> {
+    rows: &'a [Vec<i64>],
+    pivots: &'a [usize],
+    free: &'a [usize],
+    limits: &'a [i64],
+    scale: i64,
+    shares: Vec<i64>,
+    weights: Vec<i64>,
+    best: Option<i64>,
+}
+
+impl FreeSearch<'_> {
+    /// `constants` are the right-hand sides with the presses of the free
+    /// buttons before `index` already subtracted, `presses` their total.
+    fn run(&mut self, index: usize, constants: Vec<i64>, presses: i64) {
+        // The lowest the total can get from here, if the free buttons that
+        // lower it are pressed as often as they can be.
+        let pivot_presses: i64 = constants
+            .iter()
+            .zip(&self.shares)
+            .map(|(constant, share)| constant * share)
+            .sum();
+        let savings: i64 = (index..self.free.len())
+            .map(|k| self.weights[k].min(0) * self.limits[k])
+            .sum();
+        let bound = presses * self.scale + pivot_presses + savings;
+
+        if self.best.is_some_and(|best| bound >= best * self.scale) {
+            return;
+        }
+
+        if index == self.free.len() {
+            let mut total = presses;
+
+            for ((row, &column), constant) in self.rows.iter().zip(self.pivots).zip(&constants) {
+                let pivot = row[column];
+                if *constant < 0 || constant % pivot != 0 {
+                    return;
+                }
+                total += constant / pivot;
+            }
+
+            self.best = Some(self.best.map_or(total, |best| best.min(total)));
+            return;
+        }
+
+        let button = self.free[index];
+        let (mut low, mut high) = (0, self.limits[index]);
+
+        for (row, &constant) in self.rows.iter().zip(&constants) {
+            // Unless a later free button adds presses back, this pivot
+            // button mustn't go negative with this button's presses alone.
+            if self.free[index + 1..].iter().any(|&later| row[later] < 0) {
+                continue;
+            }
+
+            match row[button].cmp(&0) {
+                Ordering::Greater => high = high.min(constant.div_euclid(row[button])),
+                Ordering::Less => low = low.max(-constant.div_euclid(-row[button])),
+                Ordering::Equal if constant < 0 => return,
+                Ordering::Equal => {}
+            }
+        }
+
+        for count in low..=high {
+            let constants = self
+                .rows
+                .iter()
+                .zip(&constants)
+                .map(|(row, constant)| constant - row[button] * count)
+                .collect();
+
+            self.run(index + 1, constants, presses + count);
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    parse_input(input)
+}
+
+pub fn part1(machines: &[Machine]) -> u64 {
+    let mut total_pushes = 0;
+
+    for Machine {
+        pattern, buttons, ..
+    } in machines
+    {
+        if *pattern == 0 {
+            continue;
+        }
+
+        let mut push_count = 1;
+
+        loop {
+            if push_button_lights(*pattern, buttons, push_count) {
+                total_pushes += push_count;
+                break;
+            }
+            push_count += 1;
+        }
+    }
+
+    total_pushes
+}
+
+/// `None` if some machine can't reach its joltages at all.
+pub fn part2(machines: &[Machine]) -> Option<u64> {
+    machines.iter().map(fewest_presses).sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day10>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_unreachable_joltages() {
+        // The first machine has no button for its second counter. The second
+        // machine's only button raises both counters together, so they can't
+        // end up at different values.
+        let machines = parse("[#.] (0) {1,2}\n[##] (0,1) {3,4}\n").unwrap();
+
+        assert_eq!(None, fewest_presses(&machines[0]));
+        assert_eq!(None, fewest_presses(&machines[1]));
+        assert_eq!(None, part2(&machines));
+    }
+}
@@ -0,0 +1,186 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+pub type Graph<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+#[derive(Debug, Clone)]
+struct Counter {
+    outs: u64,
+    fft: u64,
+    dac: u64,
+    fft_dac: u64,
+}
+
+fn parse_device(name: &str) -> Result<&str, ParseError> {
+    if name.is_empty() {
+        return Err(ParseError::new(name, "a device name"));
+    }
+
+    parse::check_chars(name, |c| c.is_ascii_lowercase(), "a lowercase letter")
+}
+
+fn parse_input(input: &str) -> Result<Graph<'_>, ParseError> {
+    let mut graph = Graph::new();
+
+    for line in input.lines() {
+        let (start, end) = parse::split_once(line, ": ")?;
+        let outs = end
+            .split_whitespace()
+            .map(parse_device)
+            .collect::<Result<Vec<&str>, _>>()?;
+
+        if graph.insert(parse_device(start)?, outs).is_some() {
+            return Err(ParseError::new(start, "a device listed only once"));
+        }
+    }
+
+    if graph.is_empty() {
+        return Err(ParseError::after(input, "a device"));
+    }
+
+    Ok(graph)
+}
+
+fn reverse_graph<'a>(graph: &'a Graph) -> Graph<'a> {
+    let mut reversed_graph: Graph<'a> = HashMap::new();
+
+    for (node, outs) in graph {
+        for out in outs {
+            let targets = reversed_graph.entry(out).or_default();
+            targets.push(node);
+        }
+    }
+
+    reversed_graph
+}
+
+fn count_outs<'a>(
+    graph: &'a Graph,
+    mut cache: HashMap<&'a str, Counter>,
+    start: &'a str,
+    end: &'a str,
+) -> HashMap<&'a str, Counter> {
+    if !cache.contains_key(start) {
+        let is_fft = start == "fft";
+        let is_dac = start == "dac";
+
+        let mut counter = Counter {
+            outs: 0,
+            fft: 0,
+            dac: 0,
+            fft_dac: 0,
+        };
+
+        if let Some(nodes) = graph.get(start) {
+            for node in nodes {
+                if node == &end {
+                    counter.outs += 1;
+                } else {
+                    cache = count_outs(graph, cache, node, end);
+                    let child_counter = cache.get(node).unwrap();
+                    counter.outs += child_counter.outs;
+                    counter.fft += child_counter.fft;
+                    counter.dac += child_counter.dac;
+                    counter.fft_dac += child_counter.fft_dac;
+                }
+            }
+        }
+
+        if is_fft {
+            counter.fft += counter.outs;
+
+            if counter.dac > 0 {
+                counter.fft_dac += counter.dac;
+            }
+        }
+
+        if is_dac {
+            counter.dac += counter.outs;
+
+            if counter.fft > 0 {
+                counter.fft_dac += counter.fft;
+            }
+        }
+
+        cache.insert(start, counter);
+    }
+
+    cache
+}
+
+pub fn part1(graph: &Graph) -> u64 {
+    let mut queue: Vec<&str> = Vec::new();
+    queue.push("you");
+
+    let mut out_count = 0;
+
+    while let Some(node) = queue.pop() {
+        if node == "out" {
+            out_count += 1;
+            continue;
+        }
+
+        if let Some(next_nodes) = graph.get(node) {
+            for next_node in next_nodes {
+                queue.push(next_node);
+            }
+        }
+    }
+
+    out_count
+}
+
+pub fn part2(graph: &Graph) -> u64 {
+    let rgraph = reverse_graph(graph);
+
+    let mut queue: Vec<&str> = Vec::new();
+    queue.push("out");
+
+    let mut cache: HashMap<&str, Counter> = HashMap::new();
+
+    while let Some(node) = queue.pop() {
+        cache = count_outs(graph, cache, node, "out");
+        if let Some(next_nodes) = rgraph.get(node) {
+            let new_nodes = next_nodes.iter().filter(|n| !cache.contains_key(*n));
+            queue.extend(new_nodes);
+        }
+    }
+
+    cache.get("svr").unwrap().fft_dac
+}
+
+pub fn parse(input: &str) -> Result<Graph<'_>, ParseError> {
+    parse_input(input)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Graph<'a>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day11>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
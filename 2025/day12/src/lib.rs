@@ -0,0 +1,373 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Area {
+    width: usize,
+    height: usize,
+    required_shapes: Vec<usize>,
+}
+
+/// A cell of a shape, as `(x, y)` relative to the shape's first cell in
+/// reading order. Cells left of the first one have a negative `x`.
+type Cell = (i32, i32);
+
+#[derive(Debug)]
+pub struct Shape {
+    /// Every distinct rotation and reflection, cells in reading order.
+    orientations: Vec<Vec<Cell>>,
+    cells: usize,
+    /// The longer side of the bounding box.
+    side: usize,
+}
+
+impl Shape {
+    fn new(cells: Vec<Cell>) -> Self {
+        let width = cells.iter().map(|&(x, _)| x).max().unwrap_or(0) + 1;
+        let height = cells.iter().map(|&(_, y)| y).max().unwrap_or(0) + 1;
+
+        let mut orientations: Vec<Vec<Cell>> = Vec::new();
+        let mut current = cells.clone();
+
+        for _ in 0..2 {
+            for _ in 0..4 {
+                current = current.iter().map(|&(x, y)| (-y, x)).collect();
+
+                let orientation = normalize(&current);
+                if !orientations.contains(&orientation) {
+                    orientations.push(orientation);
+                }
+            }
+
+            current = current.iter().map(|&(x, y)| (-x, y)).collect();
+        }
+
+        Shape {
+            orientations,
+            cells: cells.len(),
+            side: width.max(height) as usize,
+        }
+    }
+}
+
+/// Sorts `cells` into reading order and moves the first one to `(0, 0)`.
+fn normalize(cells: &[Cell]) -> Vec<Cell> {
+    let mut cells = cells.to_vec();
+    cells.sort_by_key(|&(x, y)| (y, x));
+
+    let (first_x, first_y) = cells[0];
+    cells
+        .iter()
+        .map(|&(x, y)| (x - first_x, y - first_y))
+        .collect()
+}
+
+fn parse_areas(input: &str, shape_count: usize) -> Result<Vec<Area>, ParseError> {
+    let mut areas = Vec::new();
+
+    for line in input.lines() {
+        let (area_str, shape_str) = parse::split_once(line, ": ")?;
+        let (width, height) = parse::split_once(area_str, "x")?;
+
+        let width = parse::number::<usize>(width)?;
+        let height = parse::number::<usize>(height)?;
+        let shapes = shape_str
+            .split_whitespace()
+            .map(parse::number::<usize>)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if shapes.len() != shape_count {
+            return Err(ParseError::new(
+                shape_str,
+                format!("{} shape counts", shape_count),
+            ));
+        }
+
+        areas.push(Area {
+            width,
+            height,
+            required_shapes: shapes,
+        });
+    }
+
+    Ok(areas)
+}
+
+fn parse_shapes(input: &[&str]) -> Result<Vec<Shape>, ParseError> {
+    let mut shapes = Vec::new();
+
+    for (index, segment) in input.iter().enumerate() {
+        let mut lines = segment.lines();
+        let header = lines.next().unwrap_or(segment);
+        let header = parse::strip_suffix(header, ":")?;
+        if parse::number::<usize>(header)? != index {
+            return Err(ParseError::new(header, format!("shape {}", index)));
+        }
+
+        let mut cells = Vec::new();
+        for (y, line) in lines.enumerate() {
+            parse::check_chars(line, |c| c == '#' || c == '.', "'#' or '.'")?;
+            cells.extend(line.match_indices('#').map(|(x, _)| (x as i32, y as i32)));
+        }
+
+        if cells.is_empty() {
+            return Err(ParseError::after(segment.trim_end(), "a shape with a '#'"));
+        }
+
+        shapes.push(Shape::new(cells));
+    }
+
+    Ok(shapes)
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Shape>, Vec<Area>), ParseError> {
+    let mut segments: Vec<&str> = input.split("\n\n").collect();
+    let area_input = segments.pop().unwrap_or(input);
+
+    let shapes = parse_shapes(&segments)?;
+    let areas = parse_areas(area_input, shapes.len())?;
+
+    Ok((shapes, areas))
+}
+
+pub fn parse(input: &str) -> Result<(Vec<Shape>, Vec<Area>), ParseError> {
+    parse_input(input)
+}
+
+/// Fills the region in reading order. The first undecided cell is either
+/// the first cell of some present, or it stays empty, which only `slack`
+/// cells can. Before branching, the search gives up if more cells than that
+/// are already dead: empty, but out of reach of every present still to place.
+///
+/// Placing the same presents in a different order often leaves the same
+/// frontier behind, so frontiers that failed once are remembered.
+struct Packing<'a> {
+    shapes: &'a [Shape],
+    width: usize,
+    height: usize,
+    filled: Vec<bool>,
+    counts: Vec<usize>,
+    remaining: usize,
+    /// How far past its first cell a present can reach in reading order.
+    reach: usize,
+    failed: HashSet<Frontier>,
+}
+
+/// Everything the rest of a search depends on: the first undecided cell,
+/// the cells after it that placed presents may have filled, and the presents
+/// still to place. The slack follows from these.
+type Frontier = (usize, Vec<bool>, Vec<usize>);
+
+impl Packing<'_> {
+    fn fits(&self, index: usize, orientation: &[Cell]) -> bool {
+        let (x, y) = ((index % self.width) as i32, (index / self.width) as i32);
+
+        orientation.iter().all(|&(dx, dy)| {
+            let (x, y) = (x + dx, y + dy);
+            (0..self.width as i32).contains(&x)
+                && y < self.height as i32
+                && !self.filled[y as usize * self.width + x as usize]
+        })
+    }
+
+    fn set(&mut self, index: usize, orientation: &[Cell], filled: bool) {
+        for &(dx, dy) in orientation {
+            let cell = (index as i32 + dy * self.width as i32 + dx) as usize;
+            self.filled[cell] = filled;
+        }
+    }
+
+    /// How many empty cells in the window of `reach` cells from `from` no
+    /// remaining present can cover. Presents are only placed with their first
+    /// cell on an empty cell at or after `from`, so the cells in the window
+    /// can only be covered by presents that start inside it. Cells further
+    /// on are counted once the window gets to them.
+    fn dead_cells(&self, from: usize) -> usize {
+        let end = (from + self.reach).min(self.filled.len());
+        let mut coverable = vec![false; end - from];
+
+        for index in (from..end).filter(|&index| !self.filled[index]) {
+            let remaining_shapes = self
+                .shapes
+                .iter()
+                .zip(&self.counts)
+                .filter(|&(_, &count)| count > 0);
+
+            for (shape, _) in remaining_shapes {
+                for orientation in &shape.orientations {
+                    if !self.fits(index, orientation) {
+                        continue;
+                    }
+
+                    for &(dx, dy) in orientation {
+                        let cell = (index as i32 + dy * self.width as i32 + dx) as usize;
+                        if cell < end {
+                            coverable[cell - from] = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        (from..end)
+            .filter(|&index| !self.filled[index] && !coverable[index - from])
+            .count()
+    }
+
+    fn fill(&mut self, from: usize, slack: usize) -> bool {
+        if self.remaining == 0 {
+            return true;
+        }
+
+        let Some(index) = (from..self.filled.len()).find(|&index| !self.filled[index]) else {
+            return false;
+        };
+
+        let end = (index + self.reach).min(self.filled.len());
+        let frontier = (index, self.filled[index..end].to_vec(), self.counts.clone());
+
+        if self.failed.contains(&frontier) || self.dead_cells(index) > slack {
+            return false;
+        }
+
+        if self.try_presents(index, slack) || slack > 0 && self.fill(index + 1, slack - 1) {
+            return true;
+        }
+
+        self.failed.insert(frontier);
+        false
+    }
+
+    /// Tries every remaining present with its first cell on `index`.
+    fn try_presents(&mut self, index: usize, slack: usize) -> bool {
+        let shapes = self.shapes;
+        for (shape_index, shape) in shapes.iter().enumerate() {
+            if self.counts[shape_index] == 0 {
+                continue;
+            }
+
+            for orientation in &shape.orientations {
+                if !self.fits(index, orientation) {
+                    continue;
+                }
+
+                self.set(index, orientation, true);
+                self.counts[shape_index] -= 1;
+                self.remaining -= 1;
+
+                let packed = self.fill(index + 1, slack);
+
+                self.remaining += 1;
+                self.counts[shape_index] += 1;
+                self.set(index, orientation, false);
+
+                if packed {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+/// Whether all the presents the area requires fit under its tree, without
+/// overlapping but in any rotation or reflection.
+pub fn can_pack(shapes: &[Shape], area: &Area) -> bool {
+    let size = area.width * area.height;
+    let presents: usize = area.required_shapes.iter().sum();
+    let cells: usize = area
+        .required_shapes
+        .iter()
+        .zip(shapes)
+        .map(|(count, shape)| count * shape.cells)
+        .sum();
+
+    if cells > size {
+        return false;
+    }
+
+    // Every present gets a square of its own.
+    let side = shapes.iter().map(|shape| shape.side).max().unwrap_or(1);
+    if (area.width / side) * (area.height / side) >= presents {
+        return true;
+    }
+
+    // Presents turn any way, so fill along the shorter side: the frontier
+    // between filled and open cells is narrower, and repeats more often.
+    let (width, height) = (area.width.min(area.height), area.width.max(area.height));
+
+    let reach = shapes
+        .iter()
+        .flat_map(|shape| &shape.orientations)
+        .flatten()
+        .map(|&(dx, dy)| (dy * width as i32 + dx) as usize + 1)
+        .max()
+        .unwrap_or(1);
+
+    let mut packing = Packing {
+        shapes,
+        width,
+        height,
+        filled: vec![false; size],
+        counts: area.required_shapes.clone(),
+        remaining: presents,
+        reach,
+        failed: HashSet::new(),
+    };
+
+    packing.fill(0, size - cells)
+}
+
+pub fn part1((shapes, areas): &(Vec<Shape>, Vec<Area>)) -> usize {
+    areas.iter().filter(|area| can_pack(shapes, area)).count()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 12;
+
+    type Input<'a> = (Vec<Shape>, Vec<Area>);
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        part1(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::fixtures::check::<Day12>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_orientations() {
+        let (shapes, _) = parse(include_str!("../fixtures/example.txt")).unwrap();
+        let counts: Vec<usize> = shapes
+            .iter()
+            .map(|shape| shape.orientations.len())
+            .collect();
+
+        assert_eq!(vec![8, 8, 2, 4, 4, 2], counts);
+    }
+
+    #[test]
+    fn test_can_pack_either_way_round() {
+        let data = include_str!("../fixtures/example.txt");
+        let (shapes, _) = parse(data).unwrap();
+        let (_, areas) = parse(&data.replace("12x5:", "5x12:")).unwrap();
+
+        let packed: Vec<bool> = areas.iter().map(|area| can_pack(&shapes, area)).collect();
+        assert_eq!(vec![true, true, false], packed);
+    }
+}
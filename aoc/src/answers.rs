@@ -0,0 +1,206 @@
+//! The expected answers file and the regression check against it.
+//!
+//! Each non-empty line that doesn't start with `#` holds
+//! `<year> <day> <part> <answer>`. Multi-line answers are stored with their
+//! line breaks written as `\n`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::input::{InputError, Source};
+use aoc_common::solution::Answer;
+
+use crate::days::Day;
+
+const HEADER: &str = "# year day part answer\n";
+
+pub type Key = (u16, u8, u8);
+
+#[derive(Default)]
+pub struct Expected {
+    answers: BTreeMap<Key, String>,
+}
+
+impl Expected {
+    pub fn parse(data: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+
+        for (index, line) in data.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("Invalid answer on line {}: '{}'", index + 1, line);
+
+            let mut fields = line.splitn(4, ' ');
+            let mut next_field = || fields.next().ok_or_else(invalid);
+
+            let year = next_field()?.parse::<u16>().map_err(|_| invalid())?;
+            let day = next_field()?.parse::<u8>().map_err(|_| invalid())?;
+            let part = next_field()?.parse::<u8>().map_err(|_| invalid())?;
+            let answer = next_field()?.replace("\\n", "\n");
+
+            if answers.insert((year, day, part), answer).is_some() {
+                return Err(format!("Duplicate answer on line {}", index + 1));
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// Loads the answers file, treating a missing file as empty.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(data) => Self::parse(&data),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("Can't read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut data = HEADER.to_string();
+
+        for ((year, day, part), answer) in &self.answers {
+            data += &format!(
+                "{} {} {} {}\n",
+                year,
+                day,
+                part,
+                answer.replace('\n', "\\n")
+            );
+        }
+
+        fs::write(path, data)
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&str> {
+        self.answers.get(key).map(|answer| answer.as_str())
+    }
+
+    pub fn insert(&mut self, key: Key, answer: String) {
+        self.answers.insert(key, answer);
+    }
+}
+
+/// `answers.txt` at the workspace root.
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Runner crate lives inside the workspace")
+        .join("answers.txt")
+}
+
+pub enum Outcome {
+    Match,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// Solved, but nothing has been recorded for this part yet.
+    New(String),
+}
+
+pub struct Report {
+    pub key: Key,
+    pub outcome: Outcome,
+}
+
+/// Solves `day` with its input from `inputs_dir` and compares both parts with
+/// the expected answers. Returns `Ok(None)` if the input isn't available.
+pub fn check_day(
+    day: &Day,
+    inputs_dir: &Path,
+    expected: &Expected,
+) -> Result<Option<Vec<Report>>, String> {
+    let data = match Source::for_day(inputs_dir, day.year, day.day).read() {
+        Ok(data) => data,
+        Err(InputError::Missing(_)) => return Ok(None),
+        Err(err) => return Err(err.to_string()),
+    };
+
+    let (part1, part2) = (day.solve)(&data)
+        .map_err(|err| format!("Invalid input for {} day {}, {}", day.year, day.day, err))?;
+
+    let reports = [(1, part1), (2, part2)]
+        .into_iter()
+        .filter_map(|(part, answer)| {
+            let key = (day.year, day.day, part);
+
+            // An unsolved part only matters if it used to have an answer.
+            if answer == Answer::Unsolved && expected.get(&key).is_none() {
+                return None;
+            }
+
+            let actual = answer.to_string();
+
+            let outcome = match expected.get(&key) {
+                Some(expected) if expected == actual => Outcome::Match,
+                Some(expected) => Outcome::Mismatch {
+                    expected: expected.to_string(),
+                    actual,
+                },
+                None => Outcome::New(actual),
+            };
+
+            Some(Report { key, outcome })
+        })
+        .collect();
+
+    Ok(Some(reports))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use aoc_common::parse::ParseError;
+
+    use super::*;
+    use crate::bench::Timings;
+
+    fn solve(_data: &str) -> Result<(Answer, Answer), ParseError> {
+        Ok((Answer::Int(42), Answer::Unsolved))
+    }
+
+    fn time(_data: &str) -> Result<Timings, ParseError> {
+        unreachable!()
+    }
+
+    #[test]
+    fn test_unsolved_part_with_recorded_answer() {
+        let inputs_dir = env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        fs::create_dir_all(inputs_dir.join("2022")).unwrap();
+        fs::write(inputs_dir.join("2022").join("01.txt"), "").unwrap();
+
+        let day = Day {
+            year: 2022,
+            day: 1,
+            solve,
+            time,
+        };
+
+        let unrecorded = check_day(&day, &inputs_dir, &Expected::default());
+        let recorded = check_day(
+            &day,
+            &inputs_dir,
+            &Expected::parse("2022 1 1 42\n2022 1 2 7\n").unwrap(),
+        );
+        fs::remove_dir_all(&inputs_dir).unwrap();
+
+        let unrecorded = unrecorded.unwrap().unwrap();
+        assert_eq!(1, unrecorded.len());
+        assert!(matches!(&unrecorded[0].outcome, Outcome::New(actual) if actual == "42"));
+
+        let recorded = recorded.unwrap().unwrap();
+        assert_eq!(2, recorded.len());
+        assert!(matches!(recorded[0].outcome, Outcome::Match));
+        assert!(matches!(
+            &recorded[1].outcome,
+            Outcome::Mismatch { expected, actual } if expected == "7" && actual == "-"
+        ));
+    }
+}
@@ -0,0 +1,343 @@
+//! Timing of the parse and both parts of a day, and the reports that let a
+//! run be compared with an earlier one.
+//!
+//! Reports are written as CSV or JSON with one record per day and the times
+//! in nanoseconds. A part without a solution has an empty time. Either form
+//! can be read back as a baseline.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+
+const CSV_HEADER: &str = "year,day,parse_ns,part1_ns,part2_ns,total_ns";
+
+/// Differences below this are noise, whatever the relative change.
+const MIN_REGRESSION: Duration = Duration::from_millis(1);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+
+    /// The fastest time of every phase over several runs.
+    fn min(&self, other: &Timings) -> Timings {
+        let min = |a: Option<Duration>, b: Option<Duration>| match (a, b) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            _ => a.or(b),
+        };
+
+        Timings {
+            parse: self.parse.min(other.parse),
+            part1: min(self.part1, other.part1),
+            part2: min(self.part2, other.part2),
+        }
+    }
+}
+
+/// Times one run of `S` on `data`. Parts that are `Unsolved` get no time.
+pub fn time<S: Solution>(data: &str) -> Result<Timings, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(data).map_err(|err| err.locate(data))?;
+    let parse = start.elapsed();
+
+    let time_part = |part: fn(&S::Input<'_>) -> Answer| {
+        let start = Instant::now();
+        let answer = part(&input);
+        let elapsed = start.elapsed();
+
+        (answer != Answer::Unsolved).then_some(elapsed)
+    };
+
+    let part1 = time_part(S::part1);
+    let part2 = time_part(S::part2);
+
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// Runs `time` `runs` times and keeps the fastest time of each phase.
+pub fn best_of(
+    runs: usize,
+    time: fn(&str) -> Result<Timings, ParseError>,
+    data: &str,
+) -> Result<Timings, ParseError> {
+    (1..runs).try_fold(time(data)?, |best, _| Ok(best.min(&time(data)?)))
+}
+
+pub type Key = (u16, u8);
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Report {
+    pub timings: BTreeMap<Key, Timings>,
+}
+
+fn nanos(duration: Option<Duration>) -> String {
+    duration
+        .map(|duration| duration.as_nanos().to_string())
+        .unwrap_or_default()
+}
+
+impl Report {
+    pub fn to_csv(&self) -> String {
+        let mut data = format!("{}\n", CSV_HEADER);
+
+        for ((year, day), timings) in &self.timings {
+            writeln!(
+                data,
+                "{},{},{},{},{},{}",
+                year,
+                day,
+                nanos(Some(timings.parse)),
+                nanos(timings.part1),
+                nanos(timings.part2),
+                nanos(Some(timings.total()))
+            )
+            .unwrap();
+        }
+
+        data
+    }
+
+    pub fn to_json(&self) -> String {
+        let json = |duration: Option<Duration>| match duration {
+            Some(_) => nanos(duration),
+            None => "null".to_string(),
+        };
+
+        let records = self
+            .timings
+            .iter()
+            .map(|((year, day), timings)| {
+                format!(
+                    "  {{\"year\": {}, \"day\": {}, \"parse_ns\": {}, \"part1_ns\": {}, \"part2_ns\": {}, \"total_ns\": {}}}",
+                    year,
+                    day,
+                    json(Some(timings.parse)),
+                    json(timings.part1),
+                    json(timings.part2),
+                    json(Some(timings.total()))
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!("[\n{}\n]\n", records.join(",\n"))
+    }
+
+    pub fn parse_csv(data: &str) -> Result<Self, String> {
+        let mut timings = BTreeMap::new();
+        let mut lines = data.lines().enumerate();
+
+        match lines.next() {
+            Some((_, header)) if header.trim() == CSV_HEADER => {}
+            _ => return Err(format!("Expected the header '{}'", CSV_HEADER)),
+        }
+
+        for (index, line) in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let invalid = || format!("Invalid timing on line {}: '{}'", index + 1, line);
+
+            let fields = line.trim().split(',').collect::<Vec<_>>();
+            let [year, day, parse, part1, part2, _total] = fields[..] else {
+                return Err(invalid());
+            };
+
+            let (key, record) = parse_record([year, day, parse, part1, part2], invalid)?;
+            timings.insert(key, record);
+        }
+
+        Ok(Self { timings })
+    }
+
+    /// Reads back the JSON written by [`Report::to_json`].
+    pub fn parse_json(data: &str) -> Result<Self, String> {
+        let mut timings = BTreeMap::new();
+        let records = data
+            .trim()
+            .strip_prefix('[')
+            .and_then(|records| records.strip_suffix(']'))
+            .ok_or("Expected a JSON array of timings")?;
+
+        for record in records.split('}') {
+            let record = record.trim().trim_start_matches(',').trim();
+
+            if record.is_empty() {
+                continue;
+            }
+
+            let invalid = || format!("Invalid timing: '{}}}'", record);
+
+            let mut values = BTreeMap::new();
+
+            for field in record.strip_prefix('{').ok_or_else(invalid)?.split(',') {
+                let (name, value) = field.split_once(':').ok_or_else(invalid)?;
+                values.insert(name.trim().trim_matches('"'), value.trim());
+            }
+
+            let field = |name: &str| values.get(name).copied().ok_or_else(invalid);
+            let fields = [
+                field("year")?,
+                field("day")?,
+                field("parse_ns")?,
+                field("part1_ns")?,
+                field("part2_ns")?,
+            ];
+
+            let (key, record) = parse_record(fields, invalid)?;
+            timings.insert(key, record);
+        }
+
+        Ok(Self { timings })
+    }
+
+    /// Reads a report written by [`Report::save`], as JSON if `path` ends in
+    /// `.json`, otherwise as CSV.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let data = fs::read_to_string(path)
+            .map_err(|err| format!("Can't read {}: {}", path.display(), err))?;
+
+        match path.extension() {
+            Some(extension) if extension == "json" => Self::parse_json(&data),
+            _ => Self::parse_csv(&data),
+        }
+        .map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    /// Writes the report as JSON if `path` ends in `.json`, otherwise as CSV.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let data = match path.extension() {
+            Some(extension) if extension == "json" => self.to_json(),
+            _ => self.to_csv(),
+        };
+
+        fs::write(path, data).map_err(|err| format!("Can't write {}: {}", path.display(), err))
+    }
+}
+
+/// Reads the year, day and the parse and part times of one record. A part
+/// without a solution is empty in CSV and `null` in JSON.
+fn parse_record(
+    [year, day, parse, part1, part2]: [&str; 5],
+    invalid: impl Fn() -> String,
+) -> Result<(Key, Timings), String> {
+    let duration = |value: &str| match value {
+        "" | "null" => Ok(None),
+        value => value
+            .parse::<u64>()
+            .map(|nanos| Some(Duration::from_nanos(nanos)))
+            .map_err(|_| invalid()),
+    };
+
+    let key = (
+        year.parse::<u16>().map_err(|_| invalid())?,
+        day.parse::<u8>().map_err(|_| invalid())?,
+    );
+
+    let timings = Timings {
+        parse: duration(parse)?.ok_or_else(&invalid)?,
+        part1: duration(part1)?,
+        part2: duration(part2)?,
+    };
+
+    Ok((key, timings))
+}
+
+/// Whether `current` is more than `threshold` percent slower than `baseline`.
+pub fn is_regression(baseline: Duration, current: Duration, threshold: f64) -> bool {
+    current > baseline + MIN_REGRESSION
+        && current.as_secs_f64() > baseline.as_secs_f64() * (1.0 + threshold / 100.0)
+}
+
+/// Formats a duration with a unit that keeps it readable, like `1.24 ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    if nanos >= 1e9 {
+        format!("{:.2} s", nanos / 1e9)
+    } else if nanos >= 1e6 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else if nanos >= 1e3 {
+        format!("{:.2} µs", nanos / 1e3)
+    } else {
+        format!("{} ns", nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two days, one of them with an unsolved part.
+    fn sample_report() -> Report {
+        let mut report = Report::default();
+        report.timings.insert(
+            (2022, 16),
+            Timings {
+                parse: Duration::from_nanos(1_500),
+                part1: Some(Duration::from_millis(250)),
+                part2: None,
+            },
+        );
+        report.timings.insert(
+            (2023, 1),
+            Timings {
+                parse: Duration::from_nanos(10),
+                part1: Some(Duration::from_nanos(20)),
+                part2: Some(Duration::from_nanos(30)),
+            },
+        );
+
+        report
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let report = sample_report();
+        let csv = report.to_csv();
+
+        assert!(csv.contains("\n2022,16,1500,250000000,,250001500\n"));
+        assert_eq!(report, Report::parse_csv(&csv).unwrap());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let report = sample_report();
+        let json = report.to_json();
+
+        assert!(json.contains("\"part2_ns\": null"));
+        assert_eq!(report, Report::parse_json(&json).unwrap());
+        assert_eq!(Report::default(), Report::parse_json("[\n\n]\n").unwrap());
+        assert!(Report::parse_json("[{\"year\": 2022}]").is_err());
+    }
+
+    #[test]
+    fn test_is_regression() {
+        let ms = Duration::from_millis;
+
+        assert!(is_regression(ms(10), ms(13), 20.0));
+        assert!(!is_regression(ms(10), ms(11), 20.0));
+        // Relative change is large, but below the noise floor.
+        assert!(!is_regression(
+            Duration::from_micros(10),
+            Duration::from_micros(50),
+            20.0
+        ));
+    }
+}
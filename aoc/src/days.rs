@@ -0,0 +1,105 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+
+use crate::bench::{self, Timings};
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&str) -> Result<(Answer, Answer), ParseError>,
+    pub time: fn(&str) -> Result<Timings, ParseError>,
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        year: S::YEAR,
+        day: S::DAY,
+        solve: S::solve,
+        time: bench::time::<S>,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<aoc2022_day01::Day01>(),
+    day::<aoc2022_day02::Day02>(),
+    day::<aoc2022_day03::Day03>(),
+    day::<aoc2022_day04::Day04>(),
+    day::<aoc2022_day05::Day05>(),
+    day::<aoc2022_day06::Day06>(),
+    day::<aoc2022_day07::Day07>(),
+    day::<aoc2022_day08::Day08>(),
+    day::<aoc2022_day09::Day09>(),
+    day::<aoc2022_day10::Day10>(),
+    day::<aoc2022_day11::Day11>(),
+    day::<aoc2022_day12::Day12>(),
+    day::<aoc2022_day13::Day13>(),
+    day::<aoc2022_day14::Day14>(),
+    day::<aoc2022_day15::Day15>(),
+    day::<aoc2022_day16::Day16>(),
+    day::<aoc2022_day17::Day17>(),
+    day::<aoc2022_day18::Day18>(),
+    day::<aoc2022_day19::Day19>(),
+    day::<aoc2022_day20::Day20>(),
+    day::<aoc2023_day01::Day01>(),
+    day::<aoc2023_day02::Day02>(),
+    day::<aoc2023_day03::Day03>(),
+    day::<aoc2023_day04::Day04>(),
+    day::<aoc2023_day05::Day05>(),
+    day::<aoc2023_day06::Day06>(),
+    day::<aoc2023_day07::Day07>(),
+    day::<aoc2023_day08::Day08>(),
+    day::<aoc2023_day09::Day09>(),
+    day::<aoc2023_day10::Day10>(),
+    day::<aoc2023_day11::Day11>(),
+    day::<aoc2023_day12::Day12>(),
+    day::<aoc2023_day13::Day13>(),
+    day::<aoc2023_day14::Day14>(),
+    day::<aoc2023_day15::Day15>(),
+    day::<aoc2023_day16::Day16>(),
+    day::<aoc2023_day17::Day17>(),
+    day::<aoc2023_day18::Day18>(),
+    day::<aoc2023_day19::Day19>(),
+    day::<aoc2023_day20::Day20>(),
+    day::<aoc2023_day21::Day21>(),
+    day::<aoc2023_day22::Day22>(),
+    day::<aoc2023_day23::Day23>(),
+    day::<aoc2023_day24::Day24>(),
+    day::<aoc2023_day25::Day25>(),
+    day::<aoc2024_day01::Day01>(),
+    day::<aoc2024_day02::Day02>(),
+    day::<aoc2024_day03::Day03>(),
+    day::<aoc2024_day04::Day04>(),
+    day::<aoc2024_day05::Day05>(),
+    day::<aoc2024_day06::Day06>(),
+    day::<aoc2024_day07::Day07>(),
+    day::<aoc2024_day08::Day08>(),
+    day::<aoc2024_day09::Day09>(),
+    day::<aoc2024_day10::Day10>(),
+    day::<aoc2024_day11::Day11>(),
+    day::<aoc2024_day12::Day12>(),
+    day::<aoc2024_day13::Day13>(),
+    day::<aoc2024_day14::Day14>(),
+    day::<aoc2024_day15::Day15>(),
+    day::<aoc2024_day16::Day16>(),
+    day::<aoc2024_day17::Day17>(),
+    day::<aoc2024_day18::Day18>(),
+    day::<aoc2024_day19::Day19>(),
+    day::<aoc2024_day20::Day20>(),
+    day::<aoc2024_day21::Day21>(),
+    day::<aoc2024_day22::Day22>(),
+    day::<aoc2024_day23::Day23>(),
+    day::<aoc2024_day24::Day24>(),
+    day::<aoc2024_day25::Day25>(),
+    day::<aoc2025_day01::Day01>(),
+    day::<aoc2025_day02::Day02>(),
+    day::<aoc2025_day03::Day03>(),
+    day::<aoc2025_day04::Day04>(),
+    day::<aoc2025_day05::Day05>(),
+    day::<aoc2025_day06::Day06>(),
+    day::<aoc2025_day07::Day07>(),
+    day::<aoc2025_day08::Day08>(),
+    day::<aoc2025_day09::Day09>(),
+    day::<aoc2025_day10::Day10>(),
+    day::<aoc2025_day11::Day11>(),
+    day::<aoc2025_day12::Day12>(),
+];
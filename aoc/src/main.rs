@@ -0,0 +1,455 @@
+use std::cmp::Reverse;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc::answers::{self, Expected, Outcome};
+use aoc::bench::{self, Report, Timings};
+use aoc::days::{Day, DAYS};
+use aoc::table;
+use aoc_common::input::{self, InputError, Source};
+
+const USAGE: &str = "Usage:
+    aoc run <year|all> [day] [--input <file|->] [--inputs <dir>]
+    aoc check [--record] [--inputs <dir>]
+    aoc bench <year|all> [day] [--runs <n>] [--report <file>] [--baseline <file>]
+              [--threshold <percent>] [--inputs <dir>]";
+
+enum Selection {
+    All,
+    Year(u16),
+    Day(u16, u8),
+}
+
+impl Selection {
+    fn matches(&self, day: &Day) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Year(year) => day.year == *year,
+            Selection::Day(year, number) => day.year == *year && day.day == *number,
+        }
+    }
+}
+
+enum Command {
+    Run {
+        selection: Selection,
+        input: Option<Source>,
+    },
+    Check {
+        record: bool,
+    },
+    Bench {
+        selection: Selection,
+        options: BenchOptions,
+    },
+}
+
+struct BenchOptions {
+    /// Every day runs this many times and keeps its fastest timings.
+    runs: usize,
+    /// Where to write the timings, as JSON for a `.json` file, otherwise CSV.
+    report: Option<PathBuf>,
+    /// A report of an earlier run to compare with, as written by `report`.
+    baseline: Option<PathBuf>,
+    /// How many percent slower than the baseline counts as a regression.
+    threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            runs: 1,
+            report: None,
+            baseline: None,
+            threshold: 20.0,
+        }
+    }
+}
+
+struct Options {
+    command: Command,
+    inputs_dir: PathBuf,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut positional: Vec<&str> = Vec::new();
+    let mut input = None;
+    let mut record = false;
+    let mut bench_options = BenchOptions::default();
+    let mut bench_flag = None;
+    let mut inputs_dir = input::inputs_dir();
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let value = args.next().ok_or("Missing value for --input")?;
+                input = Some(Source::from_arg(value));
+            }
+            "--inputs" => {
+                let value = args.next().ok_or("Missing value for --inputs")?;
+                inputs_dir = PathBuf::from(value);
+            }
+            "--record" => record = true,
+            "--runs" | "--report" | "--baseline" | "--threshold" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))?;
+                let invalid = || format!("Invalid value for {}: '{}'", arg, value);
+
+                match arg.as_str() {
+                    "--runs" => {
+                        bench_options.runs = value
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|runs| *runs > 0)
+                            .ok_or_else(invalid)?
+                    }
+                    "--report" => bench_options.report = Some(PathBuf::from(value)),
+                    "--baseline" => bench_options.baseline = Some(PathBuf::from(value)),
+                    _ => bench_options.threshold = value.parse::<f64>().map_err(|_| invalid())?,
+                }
+
+                bench_flag = Some(arg.as_str());
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let command = match positional[..] {
+        ["run", ref selection @ ..] => Command::Run {
+            selection: parse_selection(selection)?,
+            input,
+        },
+        ["check"] if input.is_none() => Command::Check { record },
+        ["bench", ref selection @ ..] if input.is_none() => Command::Bench {
+            selection: parse_selection(selection)?,
+            options: bench_options,
+        },
+        _ => return Err(USAGE.to_string()),
+    };
+
+    if let (Some(flag), false) = (bench_flag, matches!(command, Command::Bench { .. })) {
+        return Err(format!("{} can only be used with bench", flag));
+    }
+
+    match &command {
+        Command::Run {
+            selection,
+            input: Some(_),
+        } if !matches!(selection, Selection::Day(..)) => {
+            Err("--input can only be used when running a single day".to_string())
+        }
+        Command::Run { .. } | Command::Bench { .. } if record => {
+            Err("--record can only be used with check".to_string())
+        }
+        _ => Ok(Options {
+            command,
+            inputs_dir,
+        }),
+    }
+}
+
+fn parse_selection(args: &[&str]) -> Result<Selection, String> {
+    match args {
+        ["all"] => Ok(Selection::All),
+        [year] => Ok(Selection::Year(parse_year(year)?)),
+        [year, day] => Ok(Selection::Day(parse_year(year)?, parse_day(day)?)),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn parse_year(value: &str) -> Result<u16, String> {
+    value
+        .parse::<u16>()
+        .map_err(|_| format!("Invalid year '{}'", value))
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    value
+        .parse::<u8>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("Invalid day '{}'", value))
+}
+
+fn run(day: &Day, source: &Source) -> Vec<String> {
+    let (part1, part2) = match source.read() {
+        Ok(input) => match (day.solve)(&input) {
+            Ok((part1, part2)) => (part1.to_string(), part2.to_string()),
+            Err(err) => {
+                eprintln!("Invalid input for {} day {}, {}", day.year, day.day, err);
+                ("invalid input".to_string(), "-".to_string())
+            }
+        },
+        Err(InputError::Missing(path)) => (format!("missing {}", path.display()), "-".to_string()),
+        Err(err) => (err.to_string(), "-".to_string()),
+    };
+
+    vec![day.year.to_string(), day.day.to_string(), part1, part2]
+}
+
+fn select_days(selection: &Selection) -> Result<Vec<&'static Day>, String> {
+    let days = DAYS
+        .iter()
+        .filter(|day| selection.matches(day))
+        .collect::<Vec<_>>();
+
+    if days.is_empty() {
+        return Err("No solutions found for the selection".to_string());
+    }
+
+    Ok(days)
+}
+
+fn run_days(selection: &Selection, input: Option<&Source>, inputs_dir: &Path) -> ExitCode {
+    let days = match select_days(selection) {
+        Ok(days) => days,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let rows = days
+        .into_iter()
+        .map(|day| match input {
+            Some(source) => run(day, source),
+            None => run(day, &Source::for_day(inputs_dir, day.year, day.day)),
+        })
+        .collect::<Vec<_>>();
+
+    table::print(&["Year", "Day", "Part 1", "Part 2"], &rows);
+
+    ExitCode::SUCCESS
+}
+
+fn check(record: bool, inputs_dir: &Path) -> Result<bool, String> {
+    let path = answers::answers_path();
+    let mut expected = Expected::load(&path)?;
+
+    let mut rows = Vec::new();
+    let mut skipped = 0;
+    let mut new_answers = Vec::new();
+
+    for day in DAYS {
+        let Some(reports) = answers::check_day(day, inputs_dir, &expected)? else {
+            skipped += 1;
+            continue;
+        };
+
+        for report in reports {
+            let (year, day, part) = report.key;
+            let (status, expected, actual) = match report.outcome {
+                Outcome::Match => continue,
+                Outcome::Mismatch { expected, actual } => ("MISMATCH", expected, actual),
+                Outcome::New(actual) => {
+                    new_answers.push((report.key, actual.clone()));
+                    ("new", "-".to_string(), actual)
+                }
+            };
+
+            rows.push(vec![
+                year.to_string(),
+                day.to_string(),
+                part.to_string(),
+                status.to_string(),
+                expected,
+                actual,
+            ]);
+        }
+    }
+
+    let mismatches = rows.iter().filter(|row| row[3] == "MISMATCH").count();
+
+    if !rows.is_empty() {
+        table::print(
+            &["Year", "Day", "Part", "Status", "Expected", "Actual"],
+            &rows,
+        );
+        println!();
+    }
+
+    println!(
+        "{} mismatched, {} new, {} days skipped without input",
+        mismatches,
+        new_answers.len(),
+        skipped
+    );
+
+    if record && !new_answers.is_empty() {
+        for (key, answer) in new_answers {
+            expected.insert(key, answer);
+        }
+
+        expected
+            .save(&path)
+            .map_err(|err| format!("Can't write {}: {}", path.display(), err))?;
+        println!("Recorded new answers in {}", path.display());
+    }
+
+    Ok(mismatches == 0)
+}
+
+fn format_timing(duration: Option<Duration>) -> String {
+    duration
+        .map(bench::format_duration)
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Compares every phase with the baseline and returns the slower ones.
+fn regressions(baseline: &Timings, current: &Timings, threshold: f64) -> Vec<&'static str> {
+    [
+        ("parse", Some(baseline.parse), Some(current.parse)),
+        ("part 1", baseline.part1, current.part1),
+        ("part 2", baseline.part2, current.part2),
+    ]
+    .into_iter()
+    .filter_map(|(phase, baseline, current)| match (baseline, current) {
+        (Some(baseline), Some(current)) if bench::is_regression(baseline, current, threshold) => {
+            Some(phase)
+        }
+        _ => None,
+    })
+    .collect()
+}
+
+fn bench(selection: &Selection, options: &BenchOptions, inputs_dir: &Path) -> Result<bool, String> {
+    let baseline = options.baseline.as_deref().map(Report::load).transpose()?;
+
+    let mut report = Report::default();
+    let mut skipped = 0;
+
+    for day in select_days(selection)? {
+        let data = match Source::for_day(inputs_dir, day.year, day.day).read() {
+            Ok(data) => data,
+            Err(InputError::Missing(_)) => {
+                skipped += 1;
+                continue;
+            }
+            Err(err) => return Err(err.to_string()),
+        };
+
+        let timings = bench::best_of(options.runs, day.time, &data)
+            .map_err(|err| format!("Invalid input for {} day {}, {}", day.year, day.day, err))?;
+        report.timings.insert((day.year, day.day), timings);
+    }
+
+    let mut entries = report.timings.iter().collect::<Vec<_>>();
+    entries.sort_by_key(|(_, timings)| Reverse(timings.total()));
+
+    let mut headers = vec!["Year", "Day", "Parse", "Part 1", "Part 2", "Total"];
+    if baseline.is_some() {
+        headers.extend(["Baseline", "Change", "Status"]);
+    }
+
+    let mut regressed = 0;
+
+    let rows = entries
+        .into_iter()
+        .map(|(key, timings)| {
+            let mut row = vec![
+                key.0.to_string(),
+                key.1.to_string(),
+                bench::format_duration(timings.parse),
+                format_timing(timings.part1),
+                format_timing(timings.part2),
+                bench::format_duration(timings.total()),
+            ];
+
+            if let Some(baseline) = &baseline {
+                match baseline.timings.get(key) {
+                    Some(before) => {
+                        let change = (timings.total().as_secs_f64()
+                            / before.total().as_secs_f64().max(f64::MIN_POSITIVE)
+                            - 1.0)
+                            * 100.0;
+                        let slower = regressions(before, timings, options.threshold);
+
+                        let status = if slower.is_empty() {
+                            String::new()
+                        } else {
+                            regressed += 1;
+                            format!("REGRESSION ({})", slower.join(", "))
+                        };
+
+                        row.extend([
+                            bench::format_duration(before.total()),
+                            format!("{:+.1}%", change),
+                            status,
+                        ]);
+                    }
+                    None => row.extend(["-".to_string(), "-".to_string(), "new".to_string()]),
+                }
+            }
+
+            row
+        })
+        .collect::<Vec<_>>();
+
+    if !rows.is_empty() {
+        table::print(&headers, &rows);
+        println!();
+    }
+
+    let total = report.timings.values().map(Timings::total).sum();
+    println!(
+        "{} days timed in {}, {} days skipped without input",
+        report.timings.len(),
+        bench::format_duration(total),
+        skipped
+    );
+
+    if let Some(path) = &options.baseline {
+        println!(
+            "{} days regressed by more than {}% against {}",
+            regressed,
+            options.threshold,
+            path.display()
+        );
+    }
+
+    if let Some(path) = &options.report {
+        report.save(path)?;
+        println!("Wrote report to {}", path.display());
+    }
+
+    Ok(regressed == 0)
+}
+
+fn exit_code(result: Result<bool, String>) -> ExitCode {
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match &options.command {
+        Command::Run { selection, input } => {
+            run_days(selection, input.as_ref(), &options.inputs_dir)
+        }
+        Command::Check { record } => exit_code(check(*record, &options.inputs_dir)),
+        Command::Bench {
+            selection,
+            options: bench_options,
+        } => exit_code(bench(selection, bench_options, &options.inputs_dir)),
+    }
+}
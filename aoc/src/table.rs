@@ -0,0 +1,47 @@
+/// Prints rows as an aligned table. Cells spanning multiple lines (like the
+/// ASCII art answers) are kept together and padded to the widest line.
+pub fn print(headers: &[&str], rows: &[Vec<String>]) {
+    let mut widths = headers.iter().map(|h| h.len()).collect::<Vec<_>>();
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            let cell_width = cell.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+            *width = (*width).max(cell_width);
+        }
+    }
+
+    let headers = headers.iter().map(|h| h.to_string()).collect::<Vec<_>>();
+
+    print_row(&headers, &widths);
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|&w| "-".repeat(w))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+
+    for row in rows {
+        print_row(row, &widths);
+    }
+}
+
+fn print_row(row: &[String], widths: &[usize]) {
+    let cells = row
+        .iter()
+        .map(|cell| cell.lines().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let height = cells.iter().map(|c| c.len()).max().unwrap_or(0).max(1);
+
+    for line in 0..height {
+        let text = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell.get(line).unwrap_or(&"")))
+            .collect::<Vec<_>>()
+            .join(" | ");
+
+        println!("{}", text.trim_end());
+    }
+}
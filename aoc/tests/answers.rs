@@ -0,0 +1,33 @@
+//! Runs every day that has an input in the inputs directory and compares the
+//! results with `answers.txt`. Days without an input are skipped. Use
+//! `cargo test --release -p aoc` to keep the slower days bearable.
+
+use aoc::answers::{self, Expected, Outcome};
+use aoc::days::DAYS;
+use aoc_common::input;
+
+#[test]
+fn answers_match_expected() {
+    let expected = Expected::load(&answers::answers_path()).unwrap();
+    let inputs_dir = input::inputs_dir();
+
+    let mut mismatches = Vec::new();
+
+    for day in DAYS {
+        let Some(reports) = answers::check_day(day, &inputs_dir, &expected).unwrap() else {
+            continue;
+        };
+
+        for report in reports {
+            if let Outcome::Mismatch { expected, actual } = report.outcome {
+                let (year, day, part) = report.key;
+                mismatches.push(format!(
+                    "{} day {} part {}: expected {}, got {}",
+                    year, day, part, expected, actual
+                ));
+            }
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
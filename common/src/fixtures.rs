@@ -0,0 +1,108 @@
+//! Worked examples from the puzzle descriptions, checked by every day's tests.
+//!
+//! A day keeps its examples in `fixtures/`. Each `<name>.txt` there is an
+//! example input, and `fixtures/answers.txt` lists the expected results, one
+//! per line:
+//!
+//! ```text
+//! <name> <part> <answer>
+//! <name> param <param> <value>
+//! ```
+//!
+//! Only the listed parts of an example are run. Multi-line answers are
+//! written with `\n` for their line breaks.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::solution::{Params, Solution};
+
+#[derive(Default)]
+struct Example {
+    params: Params,
+    answers: BTreeMap<u8, String>,
+}
+
+fn invalid<T>(index: usize, line: &str) -> T {
+    panic!("Invalid fixture on line {}: '{}'", index + 1, line)
+}
+
+fn parse_answers(data: &str) -> BTreeMap<String, Example> {
+    let mut examples: BTreeMap<String, Example> = BTreeMap::new();
+
+    for (index, line) in data.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields = line.splitn(4, ' ').collect::<Vec<_>>();
+
+        match fields[..] {
+            [name, "param", param, value] => {
+                let value = value
+                    .parse::<i64>()
+                    .unwrap_or_else(|_| invalid(index, line));
+                examples
+                    .entry(name.to_string())
+                    .or_default()
+                    .params
+                    .set(param, value);
+            }
+            [name, part, ..] if fields.len() >= 3 => {
+                let part = part.parse::<u8>().unwrap_or_else(|_| invalid(index, line));
+                let answer = line.splitn(3, ' ').nth(2).unwrap().replace("\\n", "\n");
+                examples
+                    .entry(name.to_string())
+                    .or_default()
+                    .answers
+                    .insert(part, answer);
+            }
+            _ => invalid(index, line),
+        }
+    }
+
+    examples
+}
+
+/// Runs every example in `<crate_dir>/fixtures` and panics listing all
+/// results that differ from `fixtures/answers.txt`.
+pub fn check<S: Solution>(crate_dir: &str) {
+    let dir = Path::new(crate_dir).join("fixtures");
+    let answers = fs::read_to_string(dir.join("answers.txt"))
+        .unwrap_or_else(|err| panic!("Can't read {}/answers.txt: {}", dir.display(), err));
+
+    let examples = parse_answers(&answers);
+    assert!(!examples.is_empty(), "No examples in {}", dir.display());
+
+    let mut failures = Vec::new();
+
+    for (name, example) in &examples {
+        let path = dir.join(format!("{}.txt", name));
+        let data = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Can't read {}: {}", path.display(), err));
+
+        let input = S::parse_with(&data, &example.params)
+            .unwrap_or_else(|err| panic!("Invalid example {}, {}", name, err.locate(&data)));
+
+        for (part, expected) in &example.answers {
+            let actual = match part {
+                1 => S::part1(&input),
+                2 => S::part2(&input),
+                _ => panic!("Invalid part {} for example {}", part, name),
+            }
+            .to_string();
+
+            if actual != *expected {
+                failures.push(format!(
+                    "{} part {}: expected {}, got {}",
+                    name, part, expected, actual
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
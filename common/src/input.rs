@@ -0,0 +1,135 @@
+//! Puzzle input loading shared by the day binaries and the runner.
+//!
+//! An input is read from, in order of preference:
+//! - an explicit path given on the command line (`-` reads stdin),
+//! - `<inputs>/<year>/<day>.txt`, where `<inputs>` is `$AOC_INPUTS` or the
+//!   `inputs/` directory at the workspace root.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+use crate::parse::ParseError;
+
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(
+                f,
+                "Input file {} not found. Pass a path (or - for stdin), or set {}",
+                path.display(),
+                INPUTS_ENV
+            ),
+            InputError::Unreadable(path, err) => {
+                write!(f, "Can't read input file {}: {}", path.display(), err)
+            }
+            InputError::Stdin(err) => write!(f, "Can't read input from stdin: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// `-` selects stdin, anything else is a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    /// The default location of the input of a day inside `inputs_dir`.
+    pub fn for_day(inputs_dir: &Path, year: u16, day: u8) -> Self {
+        Source::File(
+            inputs_dir
+                .join(year.to_string())
+                .join(format!("{:02}.txt", day)),
+        )
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::Stdin => {
+                let mut data = String::new();
+                io::stdin()
+                    .read_to_string(&mut data)
+                    .map_err(InputError::Stdin)?;
+                Ok(data)
+            }
+            Source::File(path) => fs::read_to_string(path).map_err(|err| {
+                if err.kind() == io::ErrorKind::NotFound {
+                    InputError::Missing(path.clone())
+                } else {
+                    InputError::Unreadable(path.clone(), err)
+                }
+            }),
+        }
+    }
+}
+
+/// `$AOC_INPUTS` if set, otherwise `inputs/` at the workspace root.
+pub fn inputs_dir() -> PathBuf {
+    match env::var_os(INPUTS_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("Common crate lives inside the workspace")
+            .join("inputs"),
+    }
+}
+
+/// Loads the input of a day binary: the first command-line argument that
+/// isn't a [`flag`] if present, otherwise the day's file in the inputs
+/// directory.
+pub fn load(year: u16, day: u8) -> Result<String, InputError> {
+    match env::args().skip(1).find(|arg| !arg.starts_with("--")) {
+        Some(arg) => Source::from_arg(&arg).read(),
+        None => Source::for_day(&inputs_dir(), year, day).read(),
+    }
+}
+
+/// Whether a day binary was started with `--<name>`, like `--render`.
+pub fn flag(name: &str) -> bool {
+    env::args()
+        .skip(1)
+        .any(|arg| arg.strip_prefix("--") == Some(name))
+}
+
+/// Like [`load`], but prints the error and exits instead of returning it.
+pub fn load_or_exit(year: u16, day: u8) -> String {
+    load(year, day).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1)
+    })
+}
+
+/// Runs `parse` on the input of a day binary, printing where the input is
+/// malformed and exiting if it fails.
+pub fn parse_or_exit<'a, T>(
+    data: &'a str,
+    parse: impl FnOnce(&'a str) -> Result<T, ParseError>,
+) -> T {
+    parse(data).unwrap_or_else(|err| {
+        eprintln!("Invalid input, {}", err.locate(data));
+        process::exit(1)
+    })
+}
@@ -0,0 +1,4 @@
+pub mod fixtures;
+pub mod input;
+pub mod parse;
+pub mod solution;
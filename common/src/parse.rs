@@ -0,0 +1,280 @@
+//! Errors for malformed puzzle input, and helpers for the steps every
+//! parser repeats.
+//!
+//! A parser reports an error against the slice of the input it couldn't make
+//! sense of. Every token is a slice of the original input, so the line and
+//! column are recovered afterwards by [`ParseError::locate`] and nested
+//! parsers don't need to carry the whole input around.
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, in characters.
+    pub column: usize,
+    /// The whole line the error is on.
+    pub text: String,
+    end_of_input: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    expected: String,
+    token: String,
+    address: usize,
+    location: Option<Location>,
+}
+
+impl ParseError {
+    /// `token` is the slice of the input where `expected` should have been.
+    pub fn new(token: &str, expected: impl Into<String>) -> Self {
+        Self {
+            expected: expected.into(),
+            token: token.to_string(),
+            address: token.as_ptr() as usize,
+            location: None,
+        }
+    }
+
+    /// `expected` is missing right after `text`, like a separator missing
+    /// from a line or a section missing from a truncated input.
+    pub fn after(text: &str, expected: impl Into<String>) -> Self {
+        Self::new(&text[text.len()..], expected)
+    }
+
+    /// Finds the line and column of the error in `input`. Errors about text
+    /// that isn't part of `input` are left as they are.
+    pub fn locate(mut self, input: &str) -> Self {
+        if self.location.is_some() {
+            return self;
+        }
+
+        let Some(offset) = self
+            .address
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len() && input.is_char_boundary(offset))
+        else {
+            return self;
+        };
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        self.location = Some(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            end_of_input: input[offset..].trim().is_empty(),
+        });
+
+        self
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    fn found(&self) -> String {
+        match &self.location {
+            _ if !self.token.is_empty() => quote(&self.token),
+            Some(location) if location.end_of_input => "end of input".to_string(),
+            Some(_) => "end of line".to_string(),
+            None => "nothing".to_string(),
+        }
+    }
+}
+
+/// Without a location: `expected a number, found 'x'`. With one, the line
+/// and column come first and the line is shown with the token underlined.
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some(location) = &self.location else {
+            return write!(f, "expected {}, found {}", self.expected, self.found());
+        };
+
+        writeln!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            location.line,
+            location.column,
+            self.expected,
+            self.found()
+        )?;
+
+        let number = location.line.to_string();
+        let width = self.token.lines().next().unwrap_or("").chars().count();
+
+        writeln!(f, "{} | {}", number, location.text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            " ".repeat(number.len()),
+            " ".repeat(location.column - 1),
+            "^".repeat(width.max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Quotes `text` for a message, with line breaks and other control
+/// characters escaped so the message stays on one line.
+fn quote(text: &str) -> String {
+    format!("'{}'", text.escape_debug())
+}
+
+/// Parses `token` as a number.
+pub fn number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(token, "a number"))
+}
+
+/// Like [`str::split_once`], but missing `separator` is an error.
+pub fn split_once<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::after(text, quote(separator)))
+}
+
+/// Like [`str::strip_prefix`], but missing `prefix` is an error.
+pub fn strip_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix).ok_or_else(|| {
+        let end = (0..=prefix.len().min(text.len()))
+            .rev()
+            .find(|&end| text.is_char_boundary(end))
+            .unwrap_or(0);
+        ParseError::new(&text[..end], quote(prefix))
+    })
+}
+
+/// Like [`str::strip_suffix`], but missing `suffix` is an error.
+pub fn strip_suffix<'a>(text: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    text.strip_suffix(suffix)
+        .ok_or_else(|| ParseError::after(text, quote(suffix)))
+}
+
+/// Returns `text` if all its characters are `valid`, otherwise an error at
+/// the first one that isn't.
+pub fn check_chars<'a>(
+    text: &'a str,
+    valid: impl Fn(char) -> bool,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    match text.char_indices().find(|&(_, c)| !valid(c)) {
+        Some((i, c)) => Err(ParseError::new(&text[i..i + c.len_utf8()], expected)),
+        None => Ok(text),
+    }
+}
+
+/// Splits `text` at `separator` into exactly `N` fields.
+pub fn split_n<'a, const N: usize>(
+    text: &'a str,
+    separator: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let mut fields = text.split(separator);
+    let mut result = [""; N];
+
+    for field in result.iter_mut() {
+        *field = fields
+            .next()
+            .ok_or_else(|| ParseError::after(text, quote(separator)))?;
+    }
+
+    match fields.next() {
+        Some(extra) => {
+            // Point at the separator in front of the extra field.
+            let start = extra.as_ptr() as usize - text.as_ptr() as usize - separator.len();
+            Err(ParseError::new(&text[start..], "end of line"))
+        }
+        None => Ok(result),
+    }
+}
+
+/// Splits `text` at whitespace into exactly `N` words.
+pub fn words<const N: usize>(text: &str) -> Result<[&str; N], ParseError> {
+    let mut words = text.split_whitespace();
+    let mut result = [""; N];
+
+    for word in result.iter_mut() {
+        *word = words
+            .next()
+            .ok_or_else(|| ParseError::after(text.trim_end(), format!("{} words", N)))?;
+    }
+
+    match words.next() {
+        Some(extra) => Err(ParseError::new(extra, "end of line")),
+        None => Ok(result),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "1 2\n3 x4\n";
+        let err = number::<i32>(&input[6..8]).unwrap_err().locate(input);
+
+        let location = err.location().unwrap();
+        assert_eq!((2, 3), (location.line, location.column));
+        assert_eq!(
+            "line 2, column 3: expected a number, found 'x4'\n2 | 3 x4\n  |   ^^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_missing_token() {
+        let input = "a -> b\nc\nd -> e\n";
+        let line = input.lines().nth(1).unwrap();
+
+        let err = split_once(line, " -> ").unwrap_err().locate(input);
+        assert_eq!(
+            "line 2, column 2: expected ' -> ', found end of line",
+            err.to_string().lines().next().unwrap()
+        );
+
+        let err = ParseError::after(input, "a blank line").locate(input);
+        assert!(err.to_string().contains("found end of input"));
+
+        let err = split_once("garbage\n", "\n\n").unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected '\\n\\n', found end of input",
+            err.locate("garbage\n").to_string().lines().next().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_not_from_input() {
+        let err = number::<u8>("256").unwrap_err().locate("1\n2\n");
+
+        assert_eq!(None, err.location());
+        assert_eq!("expected a number, found '256'", err.to_string());
+    }
+
+    #[test]
+    fn test_fields() {
+        assert_eq!(Ok(["a", "b", "c"]), split_n::<3>("a,b,c", ","));
+        assert_eq!(Ok(["x", "y"]), words::<2>(" x  y "));
+
+        let err = split_n::<2>("a,b,c", ",").unwrap_err();
+        assert_eq!("expected end of line, found ',c'", err.to_string());
+        assert!(words::<3>("x y").is_err());
+        assert!(strip_prefix("p=1,2", "v=").is_err());
+
+        let err = check_chars("ab3d", |c| c.is_ascii_lowercase(), "a letter").unwrap_err();
+        assert_eq!("expected a letter, found '3'", err.to_string());
+    }
+}
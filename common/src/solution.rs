@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::parse::ParseError;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// Multi-line output, like letters drawn on a screen.
+    Art(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::Text(text) | Answer::Art(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Named puzzle constants that differ between the worked examples and the
+/// real input, like the row to scan or the size of the map.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+}
+
+impl Params {
+    pub fn get(&self, name: &str, default: i64) -> i64 {
+        self.values.get(name).copied().unwrap_or(default)
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.values.insert(name.to_string(), value);
+    }
+}
+
+/// A puzzle solution. `parse` runs once and both parts share its result.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input<'a>;
+
+    fn parse(data: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    /// Parses an input that needs other constants than the real puzzle.
+    /// Days without such constants ignore `params`.
+    fn parse_with<'a>(data: &'a str, _params: &Params) -> Result<Self::Input<'a>, ParseError> {
+        Self::parse(data)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer;
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn solve(data: &str) -> Result<(Answer, Answer), ParseError> {
+        let input = Self::parse(data).map_err(|err| err.locate(data))?;
+
+        Ok((Self::part1(&input), Self::part2(&input)))
+    }
+}
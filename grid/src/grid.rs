@@ -0,0 +1,296 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use aoc_common::parse::ParseError;
+
+use crate::Point;
+
+/// Cells stored row by row. Points outside the grid are never an error to
+/// ask about: `get` returns `None` and the neighbour iterators skip them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Rows of a grid must have the same length"
+        );
+
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses one cell per character, one row per line.
+    pub fn parse(data: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            data.lines()
+                .map(|line| line.chars().map(&mut cell).collect())
+                .collect(),
+        )
+    }
+
+    /// Like [`Grid::parse`], but `cell` may reject a character. It gets the
+    /// character as a slice of `data`, so an error can point at it.
+    pub fn try_parse<'a>(
+        data: &'a str,
+        mut cell: impl FnMut(&'a str) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+
+        for line in data.lines() {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| cell(&line[i..i + c.len_utf8()]))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if let Some(width) = rows.first().map(|first| first.len()) {
+                if row.len() < width {
+                    return Err(ParseError::after(line, format!("{} cells", width)));
+                }
+                if row.len() > width {
+                    let (extra, _) = line.char_indices().nth(width).unwrap();
+                    return Err(ParseError::new(&line[extra..], "end of line"));
+                }
+            }
+
+            rows.push(row);
+        }
+
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+
+        Some(y * self.width + x)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// The cell at `point` on an endless plane tiled with copies of the grid.
+    /// Panics on an empty grid.
+    pub fn get_wrapped(&self, point: Point) -> &T {
+        let x = point.x.rem_euclid(self.width as i32) as usize;
+        let y = point.y.rem_euclid(self.height as i32) as usize;
+
+        &self.cells[y * self.width + x]
+    }
+
+    /// The neighbours north, east, south and west of `point` inside the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbors4().filter(|&p| self.contains(p))
+    }
+
+    /// The neighbours of `point` inside the grid, including the diagonals.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbors8().filter(|&p| self.contains(p))
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point, row by row, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero width.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside the grid", x);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+
+    /// The grid mirrored along its diagonal, so columns become rows.
+    pub fn transposed(&self) -> Self {
+        Self::from_rows(
+            self.columns()
+                .map(|column| column.cloned().collect())
+                .collect(),
+        )
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+/// Prints one line per row with the cells next to each other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction;
+
+    fn example() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn test_parse_and_get() {
+        let grid = example();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(Some(&'f'), grid.get(Point::new(2, 1)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(None, grid.get(Point::new(0, -1)));
+        assert_eq!('d', grid[Point::new(0, 1)]);
+        assert_eq!(&'c', grid.get_wrapped(Point::new(-1, 2)));
+        assert_eq!(&'e', grid.get_wrapped(Point::new(7, -3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_parse_ragged() {
+        Grid::parse("abc\nde\n", |c| c);
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let digit = |c: &str| aoc_common::parse::number::<u8>(c);
+
+        let grid = Grid::try_parse("12\n34\n", digit).unwrap();
+        assert_eq!(4, grid[Point::new(1, 1)]);
+
+        let data = "12\n3x\n";
+        let err = Grid::try_parse(data, digit).unwrap_err().locate(data);
+        assert_eq!(2, err.location().unwrap().column);
+
+        assert!(Grid::try_parse("12\n345\n", digit).is_err());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = example();
+
+        let neighbors = grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(vec![Point::new(1, 0), Point::new(0, 1)], neighbors);
+
+        assert_eq!(5, grid.neighbors8(Point::new(1, 0)).count());
+        assert_eq!(8, Point::new(5, 5).neighbors8().count());
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = example();
+
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!("be", grid.column(1).collect::<String>());
+        assert_eq!("ad\nbe\ncf\n", grid.transposed().to_string());
+        assert_eq!(Some(Point::new(1, 1)), grid.position(|&c| c == 'e'));
+    }
+
+    #[test]
+    fn test_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_right().turn_left());
+            assert_eq!(direction.opposite(), direction.turn_right().turn_right());
+            assert_eq!(
+                Point::default(),
+                Point::default().step(direction).step(direction.opposite())
+            );
+        }
+
+        assert_eq!(Some(Direction::South), Direction::from_arrow('v'));
+    }
+}
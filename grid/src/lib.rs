@@ -0,0 +1,14 @@
+//! A rectangular grid of cells with the points and directions to walk it.
+//!
+//! `y` grows downwards, so the first line of a puzzle input is row 0 and
+//! `North` is `y - 1`. [`Point3`] is the same idea one dimension up.
+
+mod direction;
+mod grid;
+mod point;
+mod point3;
+
+pub use direction::Direction;
+pub use grid::Grid;
+pub use point::Point;
+pub use point3::Point3;
@@ -0,0 +1,116 @@
+use std::ops::{Add, Sub};
+
+use aoc_common::parse::{self, ParseError};
+
+/// A point in space, for the puzzles about voxels and blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Parses `x,y,z`.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let [x, y, z] = parse::split_n::<3>(text, ",")?;
+
+        Ok(Self::new(
+            parse::number(x)?,
+            parse::number(y)?,
+            parse::number(z)?,
+        ))
+    }
+
+    /// The six points that share a face with this one.
+    pub fn neighbors6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |delta| self + delta)
+    }
+
+    /// The smallest of each coordinate.
+    pub fn min_each(self, other: Point3) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// The largest of each coordinate.
+    pub fn max_each(self, other: Point3) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(Point3::new(2, -3, 15)), Point3::parse("2,-3,15"));
+
+        let err = Point3::parse("1,2").unwrap_err();
+        assert_eq!("','", err.expected());
+        assert!(Point3::parse("1,x,3").is_err());
+        assert!(Point3::parse("1,2,3,4").is_err());
+    }
+
+    #[test]
+    fn test_neighbors6() {
+        let point = Point3::new(1, 2, 3);
+        let neighbours: Vec<Point3> = point.neighbors6().collect();
+
+        assert_eq!(6, neighbours.len());
+        assert!(neighbours.contains(&Point3::new(0, 2, 3)));
+        assert!(neighbours.contains(&Point3::new(1, 2, 4)));
+        assert!(neighbours.iter().all(|&neighbour| {
+            let delta = neighbour - point;
+            delta.x.abs() + delta.y.abs() + delta.z.abs() == 1
+        }));
+    }
+
+    #[test]
+    fn test_arithmetic_and_bounds() {
+        let a = Point3::new(1, 5, -2);
+        let b = Point3::new(3, -1, 4);
+
+        assert_eq!(Point3::new(4, 4, 2), a + b);
+        assert_eq!(Point3::new(-2, 6, -6), a - b);
+        assert_eq!(Point3::new(1, -1, -2), a.min_each(b));
+        assert_eq!(Point3::new(3, 5, 4), a.max_each(b));
+    }
+}
@@ -0,0 +1,293 @@
+//! Number theory shared by the puzzles: gcd and lcm, prime factors,
+//! modular arithmetic, the Chinese Remainder Theorem and linear systems
+//! modulo a prime.
+//!
+//! Everything is generic over the primitive integer types. Moduli must be
+//! positive; residues may be any value and are reduced first.
+
+mod integer;
+
+pub use integer::{Integer, Signed};
+
+use std::collections::BTreeMap;
+
+fn abs<T: Integer>(n: T) -> T {
+    if n < T::ZERO {
+        T::ZERO - n
+    } else {
+        n
+    }
+}
+
+/// `n` reduced to `0..m`, also for negative `n`.
+fn modulo<T: Integer>(n: T, m: T) -> T {
+    let r = n % m;
+
+    if r < T::ZERO {
+        r + m
+    } else {
+        r
+    }
+}
+
+/// The greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (abs(a), abs(b));
+
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple, never negative. 0 if either number is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+
+    abs(a / gcd(a, b) * b)
+}
+
+/// The least common multiple of all `numbers`, 1 if there are none.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// The prime factors of `n` with their multiplicity. Empty for 0 and 1.
+pub fn prime_factors<T: Integer>(n: T) -> BTreeMap<T, u32> {
+    let mut factors = BTreeMap::new();
+    let mut n = abs(n);
+
+    if n == T::ZERO {
+        return factors;
+    }
+
+    let mut divisor = T::ONE + T::ONE;
+    while divisor <= n / divisor {
+        while n % divisor == T::ZERO {
+            *factors.entry(divisor).or_insert(0) += 1;
+            n = n / divisor;
+        }
+        divisor = divisor + T::ONE;
+    }
+
+    if n > T::ONE {
+        *factors.entry(n).or_insert(0) += 1;
+    }
+
+    factors
+}
+
+/// Returns `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `a + b` modulo `m` for `a` and `b` already in `0..m`, without
+/// overflowing past `m`.
+fn add_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `a * b` modulo `m`. Works by doubling, so it never overflows as long as
+/// `m` itself fits.
+pub fn mul_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    let two = T::ONE + T::ONE;
+    let (mut a, mut b) = (modulo(a, m), modulo(b, m));
+    let mut result = T::ZERO;
+
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b = b / two;
+    }
+
+    result
+}
+
+/// `base` to the power `exp` modulo `m`. `exp` must not be negative.
+pub fn mod_pow<T: Integer>(base: T, exp: T, m: T) -> T {
+    let two = T::ONE + T::ONE;
+    let mut base = modulo(base, m);
+    let mut exp = exp;
+    let mut result = modulo(T::ONE, m);
+
+    while exp > T::ZERO {
+        if exp % two == T::ONE {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp = exp / two;
+    }
+
+    result
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse<T: Signed>(a: T, m: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(modulo(a, m), m);
+
+    (g == T::ONE).then(|| modulo(x, m))
+}
+
+/// Solves the system `x ≡ r (mod m)` for every `(r, m)` in `congruences`.
+///
+/// The moduli don't have to be coprime. Returns `(x, lcm)` where `x` is the
+/// smallest non-negative solution and every solution is `x` plus a multiple
+/// of `lcm`, the least common multiple of the moduli, or `None` if the
+/// congruences contradict each other.
+pub fn crt<T: Signed>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(r1, m1), (r2, m2)| {
+            let r2 = modulo(r2, m2);
+            let g = gcd(m1, m2);
+
+            if (r2 - r1) % g != T::ZERO {
+                return None;
+            }
+
+            // x = r1 + m1 * k, where m1 / g * k ≡ (r2 - r1) / g (mod m2 / g).
+            let step = m2 / g;
+            let k = mul_mod((r2 - r1) / g, mod_inverse(m1 / g, step)?, step);
+
+            Some((r1 + m1 * k, m1 * step))
+        })
+}
+
+/// The smallest number at least `from` that is congruent to `x` modulo `m`.
+pub fn next_congruent<T: Integer>(x: T, m: T, from: T) -> T {
+    from + modulo(x - modulo(from, m), m)
+}
+
+/// Solves a system of linear equations modulo the prime `p` by Gaussian
+/// elimination. Each row holds the coefficients of one equation followed by
+/// its constant. Returns the solution in `0..p`, or `None` unless there is
+/// exactly one.
+///
+/// Nothing grows beyond `p`, so an integer solution smaller than `p / 2` in
+/// magnitude comes out exactly, where eliminating over the rationals could
+/// overflow.
+pub fn solve_mod<T: Signed>(rows: &[Vec<T>], p: T) -> Option<Vec<T>> {
+    let mut rows: Vec<Vec<T>> = rows
+        .iter()
+        .map(|row| row.iter().map(|&value| modulo(value, p)).collect())
+        .collect();
+    let unknowns = rows.first()?.len() - 1;
+
+    for column in 0..unknowns {
+        let pivot = (column..rows.len()).find(|&row| rows[row][column] != T::ZERO)?;
+        rows.swap(column, pivot);
+
+        let inverse = mod_inverse(rows[column][column], p)?;
+        for value in rows[column].iter_mut() {
+            *value = mul_mod(*value, inverse, p);
+        }
+
+        let pivot_row = rows[column].clone();
+
+        for (index, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+
+            if index != column && factor != T::ZERO {
+                for (value, &pivot_value) in row.iter_mut().zip(&pivot_row) {
+                    *value = modulo(*value - mul_mod(factor, pivot_value, p), p);
+                }
+            }
+        }
+    }
+
+    // Equations beyond the number of unknowns must agree with the rest.
+    if rows[unknowns..].iter().any(|row| row[unknowns] != T::ZERO) {
+        return None;
+    }
+
+    Some(rows[..unknowns].iter().map(|row| row[unknowns]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(6, gcd(12u32, 18));
+        assert_eq!(6, gcd(-12i64, 18));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(36, lcm(12usize, 18));
+        assert_eq!(0, lcm(0, 7));
+        assert_eq!(60, lcm_all([3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(
+            BTreeMap::from([(2, 3), (3, 2), (5, 1)]),
+            prime_factors(360u64)
+        );
+        assert_eq!(BTreeMap::from([(9973, 1)]), prime_factors(9973i32));
+        assert!(prime_factors(1u8).is_empty());
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        let (g, x, y) = extended_gcd(240i64, 46);
+        assert_eq!(2, g);
+        assert_eq!(g, 240 * x + 46 * y);
+
+        assert_eq!(Some(4), mod_inverse(3i32, 11));
+        assert_eq!(None, mod_inverse(4i32, 8));
+        assert_eq!(445, mod_pow(4u64, 13, 497));
+        // (-1) * (-2), which overflows if multiplied directly.
+        assert_eq!(2, mul_mod(u64::MAX - 1, u64::MAX - 2, u64::MAX));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some((23, 105)), crt([(2i64, 3), (3, 5), (2, 7)]));
+        // Moduli sharing a factor.
+        assert_eq!(Some((10, 12)), crt([(4i64, 6), (2, 4)]));
+        assert_eq!(None, crt([(1i64, 6), (2, 4)]));
+        assert_eq!(Some((0, 1)), crt::<i64>([]));
+
+        assert_eq!(23, next_congruent(23i64, 105, 0));
+        assert_eq!(128, next_congruent(23i64, 105, 24));
+        assert_eq!(128, next_congruent(23i64, 105, 128));
+    }
+
+    #[test]
+    fn test_solve_mod() {
+        // x + y = 3, x - y = 7 and 2x + y = 8, so x = 5 and y = -2.
+        let rows = vec![vec![1i64, 1, 3], vec![1, -1, 7], vec![2, 1, 8]];
+        assert_eq!(Some(vec![5, 11]), solve_mod(&rows, 13));
+
+        assert_eq!(None, solve_mod(&[vec![1i64, 1, 3], vec![2, 2, 6]], 13));
+        assert_eq!(
+            None,
+            solve_mod(&[vec![1i64, 1, 3], vec![1, -1, 7], vec![1, 0, 0]], 13)
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Shortest-path searches over any state type.
+//!
+//! A search starts from one or more states and asks a successor function
+//! for the neighbours of every state it reaches. It returns a [`Search`]
+//! with the distance of every reached state and all predecessors on its
+//! shortest paths, so both a single path and the set of all states on any
+//! shortest path can be recovered.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path cost. `Default` must be zero and costs must not be negative.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+pub struct Search<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Search<S, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// Records reaching `state` from `from` at `cost` and returns whether it
+    /// is the shortest way to `state` found so far.
+    fn reach(&mut self, state: &S, from: Option<&S>, cost: C) -> bool {
+        match self.distances.get(state) {
+            Some(&distance) if cost > distance => false,
+            Some(&distance) if cost == distance => {
+                if let Some(from) = from {
+                    self.predecessors
+                        .entry(state.clone())
+                        .or_default()
+                        .push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(state.clone(), cost);
+                self.predecessors
+                    .insert(state.clone(), from.into_iter().cloned().collect());
+                true
+            }
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// The goal states reached at the lowest cost, in the order found.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The cost of the cheapest goal, if one was reached.
+    pub fn cost(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.distance(goal))
+    }
+
+    /// The states a shortest path to `state` can come from.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], |p| p.as_slice())
+    }
+
+    /// One shortest path from a start to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every state on any shortest path to one of `ends`, the ends included.
+    pub fn states_on_shortest_paths<'a>(&self, ends: impl IntoIterator<Item = &'a S>) -> HashSet<S>
+    where
+        S: 'a,
+    {
+        let mut states = HashSet::new();
+        let mut queue = ends
+            .into_iter()
+            .filter(|end| self.distances.contains_key(end))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        while let Some(state) = queue.pop() {
+            if states.insert(state.clone()) {
+                queue.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+
+        states
+    }
+}
+
+/// Breadth-first search where every step costs one. Explores every state
+/// reachable from `starts`.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if search.reach(&start, None, 0) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let distance = search.distances[&state] + 1;
+
+        for next in successors(&state) {
+            if search.reach(&next, Some(&state), distance) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm. Stops once every goal at the lowest cost has been
+/// found, or explores every reachable state if `is_goal` never matches.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reversed, so the `BinaryHeap` pops the lowest priority first.
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost and
+/// must be consistent for the predecessor sets to be complete.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new();
+    let mut heap = BinaryHeap::new();
+    let mut best = None;
+
+    for start in starts {
+        if search.reach(&start, None, C::default()) {
+            heap.push(Entry {
+                priority: heuristic(&start),
+                cost: C::default(),
+                state: start,
+            });
+        }
+    }
+
+    while let Some(Entry {
+        priority,
+        cost,
+        state,
+    }) = heap.pop()
+    {
+        if best.is_some_and(|best| priority > best) {
+            break;
+        }
+
+        if cost > search.distances[&state] {
+            continue;
+        }
+
+        if is_goal(&state) {
+            best = Some(cost);
+            search.goals.push(state);
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+
+            if search.reach(&next, Some(&state), next_cost) {
+                heap.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two equally short ways from 0 to 3, and a longer one through 4.
+    fn edges(state: &u32) -> Vec<(u32, u32)> {
+        match state {
+            0 => vec![(1, 1), (2, 1), (4, 1)],
+            1 | 2 => vec![(3, 1)],
+            4 => vec![(3, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs([0], |s| edges(s).into_iter().map(|(next, _)| next));
+
+        assert_eq!(Some(2), search.distance(&3));
+        assert_eq!(None, search.distance(&5));
+        assert_eq!(&[1, 2, 4], search.predecessors(&3));
+        assert!(search.goals().is_empty());
+    }
+
+    #[test]
+    fn test_dijkstra_all_shortest_paths() {
+        let search = dijkstra([0], edges, |&s| s == 3);
+
+        assert_eq!(Some(2), search.cost());
+        assert_eq!(Some(vec![0, 1, 3]), search.path(&3));
+        assert_eq!(
+            HashSet::from([0, 1, 2, 3]),
+            search.states_on_shortest_paths(search.goals())
+        );
+    }
+
+    #[test]
+    fn test_astar_on_a_line() {
+        let goal = 10i32;
+        let search = astar(
+            [0i32],
+            |&s| [(s - 1, 1), (s + 1, 1)],
+            |&s| (goal - s).abs(),
+            |&s| s == goal,
+        );
+
+        assert_eq!(Some(10), search.cost());
+        assert_eq!(11, search.path(&goal).unwrap().len());
+        // Straight to the goal, the heuristic never lets it walk backwards.
+        assert!(search.distance(&-2).is_none());
+    }
+}